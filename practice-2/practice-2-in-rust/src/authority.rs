@@ -0,0 +1,165 @@
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use solana_program::program_pack::Pack;
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+use spl_token_2022::state::Multisig;
+
+use crate::{config::Config, signer::SignerSource, token::fetch_token_program_account};
+
+/// Lets a token command act through an SPL multisig instead of a single authority signer.
+#[derive(Args)]
+pub struct MultisigArgs {
+    /// Multisig account acting as the authority
+    #[arg(long)]
+    pub multisig: Option<Pubkey>,
+
+    /// Signer of the multisig, same formats as --keypair (repeat until the threshold is met)
+    #[arg(long = "multisig-signer", requires = "multisig")]
+    pub multisig_signers: Vec<SignerSource>,
+}
+
+/// Authority of a token instruction: one keypair, or a multisig address and a subset of its signers.
+pub struct Authority {
+    pub address: Pubkey,
+    keypairs: Vec<Box<dyn Signer>>,
+    multisig_signers: Vec<Pubkey>,
+}
+
+impl Authority {
+    /// Signer list for token instructions; empty unless the authority is a multisig.
+    pub fn multisig_signers(&self) -> Vec<&Pubkey> {
+        self.multisig_signers.iter().collect()
+    }
+
+    /// Keys that have to sign the transaction.
+    pub fn signers(&self) -> Vec<&dyn Signer> {
+        self.keypairs
+            .iter()
+            .map(|keypair| keypair.as_ref())
+            .collect()
+    }
+}
+
+pub fn fetch_multisig(config: &Config, address: &Pubkey) -> Result<(Pubkey, Multisig)> {
+    let account = fetch_token_program_account(config, address)?;
+    if account.data.len() != Multisig::LEN {
+        bail!("{} is not a multisig account", address);
+    }
+    let multisig = Multisig::unpack(&account.data)
+        .with_context(|| format!("{} is not an initialized multisig", address))?;
+    Ok((account.owner, multisig))
+}
+
+impl MultisigArgs {
+    /// Resolves the authority: `single` (or the default signer) unless `--multisig` is passed.
+    /// A multisig must belong to `program_id`, the token program of the account it signs for.
+    pub fn authority(
+        &self,
+        config: &Config,
+        program_id: &Pubkey,
+        single: Option<&SignerSource>,
+        name: &str,
+    ) -> Result<Authority> {
+        let Some(address) = self.multisig else {
+            let keypair = config.signer_or_default(single, name)?;
+            status!("🔑 {}: {}", capitalize(name), keypair.pubkey());
+            return Ok(Authority {
+                address: keypair.pubkey(),
+                keypairs: vec![keypair],
+                multisig_signers: vec![],
+            });
+        };
+
+        if config.transaction.offline() {
+            return self.offline_multisig(address, name);
+        }
+
+        let (multisig_program_id, multisig) = fetch_multisig(config, &address)?;
+        if multisig_program_id != *program_id {
+            bail!(
+                "Multisig {} belongs to the token program {}, but the account it signs for is owned by {}",
+                address,
+                multisig_program_id,
+                program_id
+            );
+        }
+        let members = &multisig.signers[..multisig.n as usize];
+
+        let mut keypairs: Vec<Box<dyn Signer>> = vec![];
+        for (index, source) in self.multisig_signers.iter().enumerate() {
+            let keypair = source.signer(&format!("multisig signer #{}", index + 1))?;
+            let pubkey = keypair.pubkey();
+            if !members.contains(&pubkey) {
+                bail!("{} is not a signer of multisig {}", pubkey, address);
+            }
+            if keypairs.iter().any(|k| k.pubkey() == pubkey) {
+                bail!("Multisig signer {} is passed more than once", pubkey);
+            }
+            keypairs.push(keypair);
+        }
+
+        if keypairs.len() < multisig.m as usize {
+            bail!(
+                "Multisig {} needs {} of {} signatures, got {}: pass more --multisig-signer",
+                address,
+                multisig.m,
+                multisig.n,
+                keypairs.len()
+            );
+        }
+
+        status!(
+            "🔑 {}: multisig {} ({} of {}, {} signing)",
+            capitalize(name),
+            address,
+            multisig.m,
+            multisig.n,
+            keypairs.len()
+        );
+        let multisig_signers = keypairs.iter().map(|k| k.pubkey()).collect();
+        Ok(Authority {
+            address,
+            keypairs,
+            multisig_signers,
+        })
+    }
+
+    /// Offline the members and threshold cannot be checked, the passed signers are taken as is.
+    fn offline_multisig(&self, address: Pubkey, name: &str) -> Result<Authority> {
+        let mut keypairs: Vec<Box<dyn Signer>> = vec![];
+        for (index, source) in self.multisig_signers.iter().enumerate() {
+            let keypair = source.signer(&format!("multisig signer #{}", index + 1))?;
+            if keypairs.iter().any(|k| k.pubkey() == keypair.pubkey()) {
+                bail!(
+                    "Multisig signer {} is passed more than once",
+                    keypair.pubkey()
+                );
+            }
+            keypairs.push(keypair);
+        }
+        if keypairs.is_empty() {
+            bail!("Multisig {} needs at least one --multisig-signer", address);
+        }
+
+        status!(
+            "🔑 {}: multisig {} ({} signing, not checked offline)",
+            capitalize(name),
+            address,
+            keypairs.len()
+        );
+        let multisig_signers = keypairs.iter().map(|k| k.pubkey()).collect();
+        Ok(Authority {
+            address,
+            keypairs,
+            multisig_signers,
+        })
+    }
+}
+
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+
+use crate::{config::Config, output, token::fetch_mint, transaction};
+
+#[derive(Subcommand)]
+pub enum AccountCommand {
+    /// Create (idempotently) the associated token account of a wallet
+    Create(CreateAccountArgs),
+}
+
+#[derive(Args)]
+pub struct CreateAccountArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Wallet that will own the token account
+    #[arg(long)]
+    pub owner: Pubkey,
+}
+
+pub fn run(config: &Config, command: AccountCommand) -> Result<()> {
+    match command {
+        AccountCommand::Create(args) => create(config, args),
+    }
+}
+
+fn create(config: &Config, args: CreateAccountArgs) -> Result<()> {
+    let sender_keypair = config.fee_payer()?;
+    status!("🔑 Sender public key: {}", sender_keypair.pubkey());
+
+    // ATA залежить від програми мінта: SPL Token і Token-2022 дають різні адреси
+    let mint = fetch_mint(config, &args.mint)?;
+    let associated_token =
+        get_associated_token_address_with_program_id(&args.owner, &args.mint, &mint.program_id);
+    status!("Associated Token Address: {}", associated_token);
+
+    let create_ata_ix = create_associated_token_account_idempotent(
+        &sender_keypair.pubkey(),
+        &args.owner,
+        &args.mint,
+        &mint.program_id,
+    );
+
+    output::set("mint", args.mint.to_string());
+    output::set("owner", args.owner.to_string());
+    output::set("account", associated_token.to_string());
+
+    let Some(transaction_signature) =
+        transaction::process(config, &sender_keypair, &[], &[create_ata_ix])?
+    else {
+        return Ok(());
+    };
+    status!("✅ Associated Token Account created successfully.");
+    status!("Transaction Signature: {}", transaction_signature);
+    status!(
+        "🔗 {}",
+        config.cluster.explorer_tx_url(transaction_signature)
+    );
+    status!(
+        "Token Account: {}",
+        config.cluster.explorer_address_url(associated_token)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::instruction::approve_checked;
+
+use crate::{
+    authority::MultisigArgs,
+    config::Config,
+    output,
+    signer::SignerSource,
+    token::{fetch_mint, fetch_token_account, format_amount, parse_amount},
+    transaction,
+};
+
+#[derive(Args)]
+pub struct ApproveArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Wallet or program allowed to transfer and burn the tokens
+    #[arg(long)]
+    pub delegate: Pubkey,
+
+    /// Amount the delegate may spend, e.g. 12.5 (converted with the mint's decimals)
+    #[arg(long)]
+    pub amount: String,
+
+    /// Token account to delegate [default: associated token account of the owner]
+    #[arg(long)]
+    pub account: Option<Pubkey>,
+
+    /// Owner of the token account [default: --keypair]
+    #[arg(long, conflicts_with = "multisig")]
+    pub owner: Option<SignerSource>,
+
+    #[command(flatten)]
+    pub multisig: MultisigArgs,
+}
+
+pub fn run(config: &Config, args: ApproveArgs) -> Result<()> {
+    let fee_payer = config.fee_payer()?;
+    let mint = fetch_mint(config, &args.mint)?;
+    let owner = args
+        .multisig
+        .authority(config, &mint.program_id, args.owner.as_ref(), "owner")?;
+
+    let amount = parse_amount(&args.amount, mint.decimals)?;
+
+    let account = args.account.unwrap_or_else(|| {
+        get_associated_token_address_with_program_id(&owner.address, &args.mint, &mint.program_id)
+    });
+    let token_account = fetch_token_account(config, &account, &args.mint)?;
+    if token_account.account.owner != owner.address {
+        bail!(
+            "{} is not the owner of {}, the owner is {}",
+            owner.address,
+            account,
+            token_account.account.owner
+        );
+    }
+
+    // Approve замінює попереднього делегата, а не додає до нього
+    let previous: Option<Pubkey> = token_account.account.delegate.into();
+    if let Some(previous) = previous.filter(|previous| *previous != args.delegate) {
+        status!("⚠️  Replacing the current delegate {}", previous);
+    }
+
+    status!(
+        "🤝 Allowing {} to spend {} tokens from {}",
+        args.delegate,
+        format_amount(amount, mint.decimals),
+        account
+    );
+
+    let approve_ix = approve_checked(
+        &mint.program_id,
+        &account,
+        &args.mint,
+        &args.delegate,
+        &owner.address,
+        &owner.multisig_signers(),
+        amount,
+        mint.decimals,
+    )?;
+
+    output::set("mint", args.mint.to_string());
+    output::set("account", account.to_string());
+    output::set("delegate", args.delegate.to_string());
+    output::set("amount", amount.to_string());
+    output::set("ui_amount", format_amount(amount, mint.decimals));
+    output::set("decimals", mint.decimals);
+
+    let Some(signature) =
+        transaction::process(config, &fee_payer, &owner.signers(), &[approve_ix])?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Delegate approved, signature: {}", signature);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
@@ -0,0 +1,274 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{Context, Result};
+use clap::Args;
+use mpl_token_metadata::accounts::Metadata;
+use serde_json::json;
+use solana_account_decoder_client_types::UiAccount;
+use solana_client::{
+    rpc_request::RpcRequest,
+    rpc_response::{Response, RpcKeyedAccount},
+};
+use solana_sdk::{native_token::Sol, pubkey::Pubkey};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::{Account as TokenAccount, AccountState, Mint},
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::{
+    config::Config,
+    metaplex::{self, METAPLEX_PROGRAM_ID},
+    output,
+    token::format_amount,
+};
+
+/// `getMultipleAccounts` accepts at most 100 addresses.
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+#[derive(Args)]
+pub struct BalancesArgs {
+    /// Wallet to list [default: --keypair]
+    pub owner: Option<Pubkey>,
+}
+
+struct Holding {
+    address: Pubkey,
+    program_id: Pubkey,
+    account: TokenAccount,
+}
+
+/// Decimals and display name of a mint, from its metadata extension or Metaplex.
+struct MintSummary {
+    decimals: u8,
+    name: Option<String>,
+    symbol: Option<String>,
+}
+
+pub fn run(config: &Config, args: BalancesArgs) -> Result<()> {
+    let owner = match args.owner {
+        Some(owner) => owner,
+        None => config.default_signer()?.pubkey(),
+    };
+
+    let lamports = config
+        .rpc
+        .get_balance(&owner)
+        .context("Failed to fetch balance")?;
+
+    let mut holdings = Vec::new();
+    for program_id in [spl_token::id(), spl_token_2022::id()] {
+        holdings.extend(token_accounts(config, &owner, &program_id)?);
+    }
+
+    // BTreeMap тримає мінти в стабільному порядку
+    let mut by_mint: BTreeMap<Pubkey, Vec<&Holding>> = BTreeMap::new();
+    for holding in &holdings {
+        by_mint
+            .entry(holding.account.mint)
+            .or_default()
+            .push(holding);
+    }
+    let mints: Vec<Pubkey> = by_mint.keys().copied().collect();
+    let summaries = mint_summaries(config, &mints)?;
+
+    status!("👛 Balances of {}", owner);
+    status!("   SOL: {}", Sol(lamports));
+    output::set("owner", owner.to_string());
+    output::set("lamports", lamports);
+
+    if holdings.is_empty() {
+        status!("🪙 No token accounts");
+    } else {
+        status!(
+            "🪙 {} token accounts, {} mints",
+            holdings.len(),
+            by_mint.len()
+        );
+    }
+
+    let mut accounts_json = Vec::new();
+    let mut mints_json = Vec::new();
+    for (mint, holdings) in &by_mint {
+        let Some(summary) = summaries.get(mint) else {
+            status!("   ⚠️  Mint {} was not found, skipping its accounts", mint);
+            continue;
+        };
+        let decimals = summary.decimals;
+        let total: u64 = holdings.iter().map(|holding| holding.account.amount).sum();
+        let program_id = holdings[0].program_id;
+
+        let label = match (&summary.symbol, &summary.name) {
+            (Some(symbol), Some(name)) => format!("{} ({})", symbol, name),
+            (Some(symbol), None) => symbol.clone(),
+            (None, Some(name)) => name.clone(),
+            (None, None) => "unknown token".to_string(),
+        };
+        status!("");
+        status!("   {} · {} · {}", label, mint, program_name(&program_id));
+
+        for holding in holdings {
+            let account = &holding.account;
+            let delegate: Option<Pubkey> = account.delegate.into();
+            let associated = holding.address
+                == get_associated_token_address_with_program_id(&owner, mint, &holding.program_id);
+
+            let mut flags = Vec::new();
+            if account.state == AccountState::Frozen {
+                flags.push("🧊 frozen".to_string());
+            }
+            if let Some(delegate) = delegate {
+                flags.push(format!(
+                    "🤝 delegated {} to {}",
+                    format_amount(account.delegated_amount, decimals),
+                    delegate
+                ));
+            }
+            if !associated {
+                flags.push("auxiliary account".to_string());
+            }
+            status!(
+                "      {}: {}{}{}",
+                holding.address,
+                format_amount(account.amount, decimals),
+                if flags.is_empty() { "" } else { "  " },
+                flags.join(", ")
+            );
+
+            accounts_json.push(json!({
+                "account": holding.address.to_string(),
+                "mint": mint.to_string(),
+                "program_id": holding.program_id.to_string(),
+                "amount": account.amount.to_string(),
+                "ui_amount": format_amount(account.amount, decimals),
+                "decimals": decimals,
+                "frozen": account.state == AccountState::Frozen,
+                "delegate": delegate.map(|delegate| delegate.to_string()),
+                "delegated_amount": account.delegated_amount.to_string(),
+                "associated": associated,
+            }));
+        }
+        if holdings.len() > 1 {
+            status!(
+                "      total: {} in {} accounts",
+                format_amount(total, decimals),
+                holdings.len()
+            );
+        }
+
+        mints_json.push(json!({
+            "mint": mint.to_string(),
+            "program_id": program_id.to_string(),
+            "name": summary.name,
+            "symbol": summary.symbol,
+            "decimals": decimals,
+            "amount": total.to_string(),
+            "ui_amount": format_amount(total, decimals),
+            "accounts": holdings.len(),
+        }));
+    }
+
+    output::set("token_accounts", accounts_json);
+    output::set("mints", mints_json);
+
+    Ok(())
+}
+
+/// Token accounts of `owner` under one token program, requested as raw base64 data so they
+/// decode with `StateWithExtensions` like every other account here.
+fn token_accounts(config: &Config, owner: &Pubkey, program_id: &Pubkey) -> Result<Vec<Holding>> {
+    let response: Response<Vec<RpcKeyedAccount>> = config
+        .rpc
+        .send(
+            RpcRequest::GetTokenAccountsByOwner,
+            json!([
+                owner.to_string(),
+                { "programId": program_id.to_string() },
+                { "encoding": "base64", "commitment": config.commitment.commitment },
+            ]),
+        )
+        .with_context(|| format!("Failed to fetch token accounts of {}", owner))?;
+
+    let mut holdings = Vec::new();
+    for keyed in response.value {
+        let address: Pubkey = keyed
+            .pubkey
+            .parse()
+            .with_context(|| format!("Invalid token account address {}", keyed.pubkey))?;
+        let account = UiAccount::decode::<solana_sdk::account::Account>(&keyed.account)
+            .with_context(|| format!("Failed to decode token account {}", address))?;
+        let state = StateWithExtensions::<TokenAccount>::unpack(&account.data)
+            .with_context(|| format!("{} is not a token account", address))?;
+        holdings.push(Holding {
+            address,
+            program_id: *program_id,
+            account: state.base,
+        });
+    }
+    Ok(holdings)
+}
+
+fn mint_summaries(config: &Config, mints: &[Pubkey]) -> Result<HashMap<Pubkey, MintSummary>> {
+    let mut summaries = HashMap::new();
+    let mut without_metadata = Vec::new();
+
+    for chunk in mints.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let accounts = config
+            .rpc
+            .get_multiple_accounts(chunk)
+            .context("Failed to fetch mints")?;
+        for (mint, account) in chunk.iter().zip(accounts) {
+            let Some(account) = account else {
+                continue;
+            };
+            let Ok(state) = StateWithExtensions::<Mint>::unpack(&account.data) else {
+                continue;
+            };
+            let metadata = state.get_variable_len_extension::<TokenMetadata>().ok();
+            if metadata.is_none() {
+                without_metadata.push(*mint);
+            }
+            summaries.insert(
+                *mint,
+                MintSummary {
+                    decimals: state.base.decimals,
+                    name: metadata.as_ref().map(|metadata| metadata.name.clone()),
+                    symbol: metadata.map(|metadata| metadata.symbol),
+                },
+            );
+        }
+    }
+
+    // Класичні SPL-мінти описує Metaplex, а не розширення
+    for chunk in without_metadata.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let addresses: Vec<Pubkey> = chunk.iter().map(metaplex::metadata_address).collect();
+        let accounts = config
+            .rpc
+            .get_multiple_accounts(&addresses)
+            .context("Failed to fetch Metaplex metadata")?;
+        for (mint, account) in chunk.iter().zip(accounts) {
+            let Some(metadata) = account
+                .filter(|account| account.owner == METAPLEX_PROGRAM_ID)
+                .and_then(|account| Metadata::from_bytes(&account.data).ok())
+            else {
+                continue;
+            };
+            let data = metaplex::data_of(&metadata);
+            if let Some(summary) = summaries.get_mut(mint) {
+                summary.name = Some(data.name);
+                summary.symbol = Some(data.symbol);
+            }
+        }
+    }
+
+    Ok(summaries)
+}
+
+fn program_name(program_id: &Pubkey) -> &'static str {
+    if *program_id == spl_token::id() {
+        "SPL Token"
+    } else {
+        "Token-2022"
+    }
+}
@@ -0,0 +1,38 @@
+use anyhow::{bail, Context, Result};
+use clap::Args;
+
+use crate::{
+    config::Config,
+    sender::{self, ExpiredError, Lifetime, Outcome},
+    transaction,
+};
+
+#[derive(Args)]
+pub struct BroadcastArgs {
+    /// Fully signed transaction: base64 string or file
+    pub transaction: String,
+}
+
+pub fn run(config: &Config, args: BroadcastArgs) -> Result<()> {
+    let tx = transaction::read(&args.transaction)?;
+
+    transaction::print_signers(&tx);
+    if !transaction::missing_signers(&tx).is_empty() {
+        bail!("Transaction is not fully signed, add the missing signatures with `spl sign`");
+    }
+    tx.verify_and_hash_message()
+        .context("Transaction has an invalid signature")?;
+
+    let signature = match sender::send_and_confirm(config, &tx, &Lifetime::of(&tx))? {
+        Outcome::Confirmed(signature) => signature,
+        Outcome::Expired(signature) => {
+            return Err(anyhow::Error::new(ExpiredError(signature))
+                .context("Sign it again with a fresh --blockhash or a --nonce"))
+        }
+    };
+
+    status!("✅ Transaction confirmed, signature: {}", signature);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anyhow::Result;
+use clap::Args;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::instruction::burn_checked;
+
+use crate::{
+    authority::MultisigArgs,
+    config::Config,
+    output,
+    signer::SignerSource,
+    token::{format_amount, parse_amount, OfflineMintArgs},
+    transaction,
+};
+
+#[derive(Args)]
+pub struct BurnArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Amount in tokens, e.g. 12.5 (converted with the mint's decimals)
+    #[arg(long)]
+    pub amount: String,
+
+    /// Token account to burn from [default: associated token account of the owner]
+    #[arg(long)]
+    pub account: Option<Pubkey>,
+
+    /// Owner (or delegate) of the token account [default: --keypair]
+    #[arg(long, conflicts_with = "multisig")]
+    pub owner: Option<SignerSource>,
+
+    #[command(flatten)]
+    pub multisig: MultisigArgs,
+
+    #[command(flatten)]
+    pub offline: OfflineMintArgs,
+}
+
+pub fn run(config: &Config, args: BurnArgs) -> Result<()> {
+    let fee_payer = config.fee_payer()?;
+    let mint = args.offline.mint(config, &args.mint)?;
+    let owner = args
+        .multisig
+        .authority(config, &mint.program_id, args.owner.as_ref(), "owner")?;
+
+    let amount = parse_amount(&args.amount, mint.decimals)?;
+
+    let account = args.account.unwrap_or_else(|| {
+        get_associated_token_address_with_program_id(&owner.address, &args.mint, &mint.program_id)
+    });
+
+    status!(
+        "🔥 Burning {} tokens from {}",
+        format_amount(amount, mint.decimals),
+        account
+    );
+
+    let burn_ix = burn_checked(
+        &mint.program_id,
+        &account,
+        &args.mint,
+        &owner.address,
+        &owner.multisig_signers(),
+        amount,
+        mint.decimals,
+    )?;
+
+    output::set("mint", args.mint.to_string());
+    output::set("account", account.to_string());
+    output::set("amount", amount.to_string());
+    output::set("ui_amount", format_amount(amount, mint.decimals));
+    output::set("decimals", mint.decimals);
+
+    let Some(signature) = transaction::process(config, &fee_payer, &owner.signers(), &[burn_ix])?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Tokens burned, signature: {}", signature);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::instruction::close_account;
+
+use crate::{
+    authority::MultisigArgs,
+    config::Config,
+    output,
+    signer::SignerSource,
+    token::{fetch_mint, fetch_token_account, format_amount},
+    transaction,
+};
+
+#[derive(Args)]
+pub struct CloseAccountArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Token account to close [default: associated token account of the owner]
+    #[arg(long)]
+    pub account: Option<Pubkey>,
+
+    /// Wallet that receives the rent (and wrapped SOL) [default: the owner]
+    #[arg(long)]
+    pub recipient: Option<Pubkey>,
+
+    /// Owner, or close authority if one is set, of the token account [default: --keypair]
+    #[arg(long, conflicts_with = "multisig")]
+    pub owner: Option<SignerSource>,
+
+    #[command(flatten)]
+    pub multisig: MultisigArgs,
+}
+
+pub fn run(config: &Config, args: CloseAccountArgs) -> Result<()> {
+    let fee_payer = config.fee_payer()?;
+    let mint = fetch_mint(config, &args.mint)?;
+    let authority =
+        args.multisig
+            .authority(config, &mint.program_id, args.owner.as_ref(), "owner")?;
+
+    let account = args.account.unwrap_or_else(|| {
+        get_associated_token_address_with_program_id(
+            &authority.address,
+            &args.mint,
+            &mint.program_id,
+        )
+    });
+    let token_account = fetch_token_account(config, &account, &args.mint)?;
+    let state = &token_account.account;
+
+    // Якщо close authority задано, закрити може лише вона, а не власник
+    let close_authority: Option<Pubkey> = state.close_authority.into();
+    let expected = close_authority.unwrap_or(state.owner);
+    if authority.address != expected {
+        bail!(
+            "{} cannot close {}, it needs the {} {}",
+            authority.address,
+            account,
+            if close_authority.is_some() {
+                "close authority"
+            } else {
+                "owner"
+            },
+            expected
+        );
+    }
+
+    // Wrapped SOL закривається з балансом, він повертається отримувачу
+    if state.amount > 0 && !state.is_native() {
+        bail!(
+            "Token account {} still holds {} tokens, burn or transfer them first",
+            account,
+            format_amount(state.amount, mint.decimals)
+        );
+    }
+    if token_account.withheld_amount > 0 {
+        bail!(
+            "Token account {} holds {} withheld transfer fees, harvest them to the mint first",
+            account,
+            format_amount(token_account.withheld_amount, mint.decimals)
+        );
+    }
+
+    let recipient = args.recipient.unwrap_or(authority.address);
+    status!(
+        "🗑️  Closing token account {}, rent goes to {}",
+        account,
+        recipient
+    );
+
+    let close_ix = close_account(
+        &mint.program_id,
+        &account,
+        &recipient,
+        &authority.address,
+        &authority.multisig_signers(),
+    )?;
+
+    output::set("mint", args.mint.to_string());
+    output::set("account", account.to_string());
+    output::set("recipient", recipient.to_string());
+
+    let Some(signature) =
+        transaction::process(config, &fee_payer, &authority.signers(), &[close_ix])?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Token account closed, signature: {}", signature);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
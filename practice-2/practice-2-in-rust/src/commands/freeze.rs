@@ -0,0 +1,124 @@
+use anyhow::{bail, Result};
+use clap::{ArgGroup, Args};
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::{
+    instruction::{freeze_account, thaw_account},
+    state::AccountState,
+};
+
+use crate::{
+    authority::MultisigArgs,
+    config::Config,
+    output,
+    signer::SignerSource,
+    token::{fetch_mint, fetch_token_account},
+    transaction,
+};
+
+/// Shared by `freeze` and `thaw`, which take the same accounts.
+#[derive(Args)]
+#[command(group(ArgGroup::new("target").required(true).args(["owner", "account"])))]
+pub struct FreezeArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Wallet whose associated token account is frozen or thawed
+    #[arg(long)]
+    pub owner: Option<Pubkey>,
+
+    /// Token account to freeze or thaw
+    #[arg(long)]
+    pub account: Option<Pubkey>,
+
+    /// Freeze authority signer [default: --keypair]
+    #[arg(long, conflicts_with = "multisig")]
+    pub freeze_authority: Option<SignerSource>,
+
+    #[command(flatten)]
+    pub multisig: MultisigArgs,
+}
+
+pub fn freeze(config: &Config, args: FreezeArgs) -> Result<()> {
+    run(config, args, true)
+}
+
+pub fn thaw(config: &Config, args: FreezeArgs) -> Result<()> {
+    run(config, args, false)
+}
+
+fn run(config: &Config, args: FreezeArgs, freeze: bool) -> Result<()> {
+    let fee_payer = config.fee_payer()?;
+    let mint = fetch_mint(config, &args.mint)?;
+    let authority = args.multisig.authority(
+        config,
+        &mint.program_id,
+        args.freeze_authority.as_ref(),
+        "freeze authority",
+    )?;
+
+    match mint.freeze_authority {
+        Some(freeze_authority) if freeze_authority == authority.address => {}
+        Some(freeze_authority) => bail!(
+            "{} is not the freeze authority of {}, the authority is {}",
+            authority.address,
+            args.mint,
+            freeze_authority
+        ),
+        None => bail!("Mint {} has no freeze authority", args.mint),
+    }
+
+    let account = match (args.account, args.owner) {
+        (Some(account), _) => account,
+        (None, Some(owner)) => {
+            get_associated_token_address_with_program_id(&owner, &args.mint, &mint.program_id)
+        }
+        (None, None) => unreachable!("clap requires --owner or --account"),
+    };
+    let token_account = fetch_token_account(config, &account, &args.mint)?;
+    match (freeze, token_account.account.state) {
+        (true, AccountState::Frozen) => bail!("Token account {} is already frozen", account),
+        (false, AccountState::Initialized) => bail!("Token account {} is not frozen", account),
+        _ => {}
+    }
+
+    let instruction = if freeze {
+        status!("🧊 Freezing token account {}", account);
+        freeze_account(
+            &mint.program_id,
+            &account,
+            &args.mint,
+            &authority.address,
+            &authority.multisig_signers(),
+        )?
+    } else {
+        status!("☀️  Thawing token account {}", account);
+        thaw_account(
+            &mint.program_id,
+            &account,
+            &args.mint,
+            &authority.address,
+            &authority.multisig_signers(),
+        )?
+    };
+
+    output::set("mint", args.mint.to_string());
+    output::set("account", account.to_string());
+    output::set("frozen", freeze);
+
+    let Some(signature) =
+        transaction::process(config, &fee_payer, &authority.signers(), &[instruction])?
+    else {
+        return Ok(());
+    };
+
+    if freeze {
+        status!("✅ Token account frozen, signature: {}", signature);
+    } else {
+        status!("✅ Token account thawed, signature: {}", signature);
+    }
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
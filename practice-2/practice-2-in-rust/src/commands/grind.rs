@@ -0,0 +1,282 @@
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Args;
+use serde_json::json;
+use solana_sdk::{
+    signature::Keypair,
+    signer::{keypair::write_keypair_file, Signer},
+};
+
+use crate::output;
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Args)]
+pub struct GrindArgs {
+    /// Address prefix and how many keypairs to find, PREFIX:COUNT (repeatable)
+    #[arg(long = "starts-with", value_name = "PREFIX:COUNT")]
+    pub starts_with: Vec<String>,
+
+    /// Address suffix and how many keypairs to find, SUFFIX:COUNT (repeatable)
+    #[arg(long = "ends-with", value_name = "SUFFIX:COUNT")]
+    pub ends_with: Vec<String>,
+
+    /// Prefix, suffix and count, PREFIX:SUFFIX:COUNT (repeatable)
+    #[arg(long = "starts-and-ends-with", value_name = "PREFIX:SUFFIX:COUNT")]
+    pub starts_and_ends_with: Vec<String>,
+
+    /// Match patterns case-insensitively
+    #[arg(long)]
+    pub ignore_case: bool,
+
+    /// Worker threads [default: all cores]
+    #[arg(long)]
+    pub num_threads: Option<usize>,
+
+    /// Directory for the found keypair files
+    #[arg(long, default_value = ".")]
+    pub outdir: PathBuf,
+}
+
+struct Pattern {
+    prefix: String,
+    suffix: String,
+    remaining: AtomicU64,
+    // Ймовірність збігу за одну спробу
+    probability: f64,
+}
+
+impl Pattern {
+    fn new(prefix: &str, suffix: &str, count: &str, ignore_case: bool) -> Result<Self> {
+        let count: u64 = count
+            .parse()
+            .with_context(|| format!("Invalid count '{}'", count))?;
+        if count == 0 {
+            bail!("Count must be at least 1");
+        }
+        if prefix.is_empty() && suffix.is_empty() {
+            bail!("Pattern must not be empty");
+        }
+
+        let mut probability = 1.0;
+        for c in prefix.chars().chain(suffix.chars()) {
+            let variants = BASE58_ALPHABET
+                .chars()
+                .filter(|a| {
+                    if ignore_case {
+                        a.eq_ignore_ascii_case(&c)
+                    } else {
+                        *a == c
+                    }
+                })
+                .count();
+            if variants == 0 {
+                bail!(
+                    "'{}' is not a base58 character (0, O, I and l are excluded)",
+                    c
+                );
+            }
+            probability *= variants as f64 / 58.0;
+        }
+
+        let normalize = |s: &str| {
+            if ignore_case {
+                s.to_lowercase()
+            } else {
+                s.to_string()
+            }
+        };
+
+        Ok(Self {
+            prefix: normalize(prefix),
+            suffix: normalize(suffix),
+            remaining: AtomicU64::new(count),
+            probability,
+        })
+    }
+
+    fn matches(&self, address: &str) -> bool {
+        address.starts_with(&self.prefix) && address.ends_with(&self.suffix)
+    }
+
+    /// Reserves one slot; false when this pattern already has enough keypairs.
+    fn take(&self) -> bool {
+        self.remaining
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+    }
+}
+
+fn parse_patterns(args: &GrindArgs) -> Result<Vec<Pattern>> {
+    let mut patterns = vec![];
+
+    for value in &args.starts_with {
+        let (prefix, count) = value
+            .split_once(':')
+            .ok_or_else(|| anyhow!("Expected PREFIX:COUNT, got '{}'", value))?;
+        patterns.push(Pattern::new(prefix, "", count, args.ignore_case)?);
+    }
+    for value in &args.ends_with {
+        let (suffix, count) = value
+            .split_once(':')
+            .ok_or_else(|| anyhow!("Expected SUFFIX:COUNT, got '{}'", value))?;
+        patterns.push(Pattern::new("", suffix, count, args.ignore_case)?);
+    }
+    for value in &args.starts_and_ends_with {
+        let parts: Vec<&str> = value.split(':').collect();
+        let [prefix, suffix, count] = parts.as_slice() else {
+            bail!("Expected PREFIX:SUFFIX:COUNT, got '{}'", value);
+        };
+        patterns.push(Pattern::new(prefix, suffix, count, args.ignore_case)?);
+    }
+
+    if patterns.is_empty() {
+        bail!("Pass at least one of --starts-with, --ends-with or --starts-and-ends-with");
+    }
+    Ok(patterns)
+}
+
+fn format_duration(seconds: f64) -> String {
+    if !seconds.is_finite() {
+        return "unknown".to_string();
+    }
+    let seconds = seconds as u64;
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m {}s", seconds / 60, seconds % 60),
+        3600..=86399 => format!("{}h {}m", seconds / 3600, seconds % 3600 / 60),
+        _ => format!("{}d {}h", seconds / 86400, seconds % 86400 / 3600),
+    }
+}
+
+pub fn run(args: GrindArgs) -> Result<()> {
+    let patterns = parse_patterns(&args)?;
+    let num_threads = match args.num_threads {
+        Some(0) => bail!("--num-threads must be at least 1"),
+        Some(n) => n,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+
+    fs::create_dir_all(&args.outdir)
+        .with_context(|| format!("Failed to create {}", args.outdir.display()))?;
+
+    status!("⛏️  Grinding with {} threads", num_threads);
+    for pattern in &patterns {
+        status!(
+            "   prefix '{}' suffix '{}' x{}: ~1 in {:.0} attempts",
+            pattern.prefix,
+            pattern.suffix,
+            pattern.remaining.load(Ordering::SeqCst),
+            1.0 / pattern.probability
+        );
+    }
+
+    let attempts = AtomicU64::new(0);
+    let done = AtomicBool::new(false);
+    let errors: Mutex<Vec<anyhow::Error>> = Mutex::new(vec![]);
+    let found = Mutex::new(vec![]);
+    let started = Instant::now();
+
+    let all_found = || {
+        patterns
+            .iter()
+            .all(|pattern| pattern.remaining.load(Ordering::SeqCst) == 0)
+    };
+
+    thread::scope(|scope| {
+        for _ in 0..num_threads {
+            scope.spawn(|| {
+                while !done.load(Ordering::Relaxed) {
+                    let keypair = Keypair::new();
+                    let address = keypair.pubkey().to_string();
+                    let candidate = if args.ignore_case {
+                        address.to_lowercase()
+                    } else {
+                        address.clone()
+                    };
+                    attempts.fetch_add(1, Ordering::Relaxed);
+
+                    if !patterns.iter().any(|p| p.matches(&candidate) && p.take()) {
+                        continue;
+                    }
+
+                    let file = args.outdir.join(format!("{}.json", address));
+                    match write_keypair_file(&keypair, &file) {
+                        Ok(_) => {
+                            status!("✅ Found {} -> {}", address, file.display());
+                            found
+                                .lock()
+                                .unwrap()
+                                .push(json!({ "pubkey": address, "file": file }));
+                        }
+                        Err(err) => {
+                            errors.lock().unwrap().push(anyhow!(
+                                "Failed to write {}: {}",
+                                file.display(),
+                                err
+                            ));
+                            done.store(true, Ordering::Relaxed);
+                        }
+                    }
+
+                    if all_found() {
+                        done.store(true, Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+
+        // Звіт про швидкість та орієнтовний час до завершення
+        scope.spawn(|| {
+            let mut last_report = Instant::now();
+            while !done.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(100));
+                if last_report.elapsed() < PROGRESS_INTERVAL {
+                    continue;
+                }
+                last_report = Instant::now();
+
+                let total = attempts.load(Ordering::Relaxed);
+                let rate = total as f64 / started.elapsed().as_secs_f64();
+                let remaining_attempts = patterns
+                    .iter()
+                    .map(|p| p.remaining.load(Ordering::SeqCst) as f64 / p.probability)
+                    .fold(0.0, f64::max);
+                status!(
+                    "⏱️  {} attempts, {:.0}/s, ETA ~{}",
+                    total,
+                    rate,
+                    format_duration(remaining_attempts / rate)
+                );
+            }
+        });
+    });
+
+    if let Some(err) = errors.into_inner().unwrap().pop() {
+        return Err(err);
+    }
+
+    let elapsed = started.elapsed().as_secs_f64();
+    let total = attempts.load(Ordering::Relaxed);
+    output::set("keypairs", found.into_inner().unwrap());
+    output::set("attempts", total);
+    status!(
+        "🏁 Done: {} attempts in {} ({:.0}/s)",
+        total,
+        format_duration(elapsed),
+        total as f64 / elapsed
+    );
+
+    Ok(())
+}
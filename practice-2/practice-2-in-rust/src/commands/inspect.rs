@@ -0,0 +1,621 @@
+use anchor_lang::AnchorDeserialize;
+use anyhow::{Context, Result};
+use clap::Args;
+use mpl_token_metadata::accounts::Metadata;
+use serde_json::{json, Map, Value};
+use solana_client::nonce_utils;
+use solana_loader_v3_interface::state::UpgradeableLoaderState;
+use solana_program::program_pack::Pack;
+use solana_sdk::{account::Account, hash::hashv, native_token::Sol, pubkey, pubkey::Pubkey};
+use solana_sdk_ids::{bpf_loader_upgradeable, system_program};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::{
+    extension::{
+        cpi_guard::CpiGuard,
+        default_account_state::DefaultAccountState,
+        group_member_pointer::GroupMemberPointer,
+        group_pointer::GroupPointer,
+        interest_bearing_mint::InterestBearingConfig,
+        memo_transfer::MemoTransfer,
+        metadata_pointer::MetadataPointer,
+        mint_close_authority::MintCloseAuthority,
+        pausable::PausableConfig,
+        permanent_delegate::PermanentDelegate,
+        scaled_ui_amount::ScaledUiAmountConfig,
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        transfer_hook::{TransferHook, TransferHookAccount},
+        BaseState, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    state::{Account as TokenAccount, AccountState, Mint, Multisig},
+};
+use spl_token_group_interface::state::{TokenGroup, TokenGroupMember};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use super::{
+    lookup_table::{self, ShowLookupTableArgs},
+    metadata::print_metadata,
+    multisig::{self, ShowMultisigArgs},
+    nonce::{self, ShowNonceArgs},
+};
+use crate::{
+    config::Config,
+    metaplex::{self, METAPLEX_PROGRAM_ID},
+    output,
+    token::{fetch_mint, format_amount},
+};
+
+/// Escrow program of practice-3 and practice-4, both deploy to this address.
+const ESCROW_PROGRAM_ID: Pubkey = pubkey!("87s8jsU1EEeufgewvpgFnxZRUZ8YE3YogXJsNmSbet28");
+const FAVORITES_PROGRAM_ID: Pubkey = pubkey!("BZ6pKZaNNye6FpawY2V4EgBBdVmBkYRP6Z3GB68cAfGJ");
+const FAVORITES_2_PROGRAM_ID: Pubkey = pubkey!("AHTQzuPoxEM8AnKorFWxX6DwUbswoPBRuLFHHrQ1FuYM");
+
+#[derive(Args)]
+pub struct InspectArgs {
+    /// Any account address: wallet, mint, token account, multisig, nonce, program...
+    pub address: Pubkey,
+}
+
+/// `Offer` account of the escrow program.
+struct Offer {
+    id: u64,
+    maker: Pubkey,
+    token_mint_a: Pubkey,
+    token_mint_b: Pubkey,
+    token_b_wanted_amount: u64,
+    bump: u8,
+}
+
+impl Offer {
+    /// Borsh fields in declaration order, after the discriminator.
+    fn decode(data: &mut &[u8]) -> std::io::Result<Self> {
+        Ok(Offer {
+            id: u64::deserialize(data)?,
+            maker: Pubkey::deserialize(data)?,
+            token_mint_a: Pubkey::deserialize(data)?,
+            token_mint_b: Pubkey::deserialize(data)?,
+            token_b_wanted_amount: u64::deserialize(data)?,
+            bump: u8::deserialize(data)?,
+        })
+    }
+}
+
+pub fn run(config: &Config, args: InspectArgs) -> Result<()> {
+    let address = args.address;
+    let Some(account) = config
+        .rpc
+        .get_account_with_commitment(&address, config.commitment)
+        .with_context(|| format!("Failed to fetch account {}", address))?
+        .value
+    else {
+        status!("🕳️  Account {} does not exist", address);
+        output::set("address", address.to_string());
+        output::set("exists", false);
+        return Ok(());
+    };
+
+    output::set("address", address.to_string());
+    output::set("exists", true);
+    output::set("owner", account.owner.to_string());
+    output::set("owner_name", program_name(&account.owner));
+    output::set("lamports", account.lamports);
+    output::set("data_len", account.data.len());
+    output::set("executable", account.executable);
+
+    status!("🔍 Account {}", address);
+    match program_name(&account.owner) {
+        Some(name) => status!("   owner: {} ({})", account.owner, name),
+        None => status!("   owner: {}", account.owner),
+    }
+    status!("   balance: {}", Sol(account.lamports));
+    status!("   data: {} bytes", account.data.len());
+    if account.executable {
+        status!("   executable: yes");
+    }
+
+    let kind = inspect(config, &address, &account)?;
+    output::set("kind", kind);
+    status!("🔗 {}", config.cluster.explorer_address_url(address));
+
+    Ok(())
+}
+
+/// Decodes the account data by owner program and returns the kind of the account.
+fn inspect(config: &Config, address: &Pubkey, account: &Account) -> Result<&'static str> {
+    let owner = &account.owner;
+
+    if *owner == system_program::id() {
+        if account.data.is_empty() {
+            status!("👛 System account (wallet)");
+            return Ok("wallet");
+        }
+        if nonce_utils::data_from_account(account).is_ok() {
+            nonce::show(config, ShowNonceArgs { address: *address })?;
+            return Ok("nonce");
+        }
+        status!(
+            "❔ System account with {} bytes of data",
+            account.data.len()
+        );
+        return Ok("unknown");
+    }
+
+    if *owner == spl_token::id() || *owner == spl_token_2022::id() {
+        // Multisig має ту саму довжину в обох програмах і не має розширень
+        if account.data.len() == Multisig::LEN {
+            multisig::show(config, ShowMultisigArgs { address: *address })?;
+            return Ok("multisig");
+        }
+        if let Ok(state) = StateWithExtensions::<Mint>::unpack(&account.data) {
+            inspect_mint(config, address, owner, &state)?;
+            return Ok("mint");
+        }
+        if let Ok(state) = StateWithExtensions::<TokenAccount>::unpack(&account.data) {
+            inspect_token_account(config, address, owner, &state)?;
+            return Ok("token_account");
+        }
+        status!("❔ Token program account that is neither a mint, a token account nor a multisig");
+        return Ok("unknown");
+    }
+
+    if *owner == METAPLEX_PROGRAM_ID {
+        if let Ok(metadata) = Metadata::from_bytes(&account.data) {
+            metaplex::print(&metadata);
+            return Ok("metaplex_metadata");
+        }
+    }
+
+    if *owner == solana_address_lookup_table_interface::program::id() {
+        lookup_table::show(config, ShowLookupTableArgs { address: *address })?;
+        return Ok("lookup_table");
+    }
+
+    if *owner == bpf_loader_upgradeable::id() {
+        if let Ok(state) = bincode::deserialize::<UpgradeableLoaderState>(&account.data) {
+            return Ok(inspect_loader_state(&state));
+        }
+    }
+
+    if let Some(kind) = inspect_anchor(config, account)? {
+        return Ok(kind);
+    }
+
+    if account.executable {
+        status!("⚙️  Program");
+        return Ok("program");
+    }
+    status!("❔ Unknown account data");
+    Ok("unknown")
+}
+
+fn inspect_mint(
+    config: &Config,
+    address: &Pubkey,
+    program_id: &Pubkey,
+    state: &StateWithExtensions<Mint>,
+) -> Result<()> {
+    let mint = &state.base;
+    let mint_authority: Option<Pubkey> = mint.mint_authority.into();
+    let freeze_authority: Option<Pubkey> = mint.freeze_authority.into();
+
+    status!("🪙 Mint ({})", token_program_name(program_id));
+    status!("   supply: {}", format_amount(mint.supply, mint.decimals));
+    status!("   decimals: {}", mint.decimals);
+    status!("   mint authority: {}", or_none(mint_authority));
+    status!("   freeze authority: {}", or_none(freeze_authority));
+
+    output::set("mint", address.to_string());
+    output::set("program_id", program_id.to_string());
+    output::set("supply", mint.supply.to_string());
+    output::set("ui_supply", format_amount(mint.supply, mint.decimals));
+    output::set("decimals", mint.decimals);
+    output::set("mint_authority", mint_authority.map(|key| key.to_string()));
+    output::set(
+        "freeze_authority",
+        freeze_authority.map(|key| key.to_string()),
+    );
+    output::set("extensions", extensions(state)?);
+
+    if let Ok(metadata) = state.get_variable_len_extension::<TokenMetadata>() {
+        print_metadata(&metadata);
+    } else if let Some(metadata) = metaplex::fetch(config, address)? {
+        metaplex::print(&metadata);
+    }
+
+    Ok(())
+}
+
+fn inspect_token_account(
+    config: &Config,
+    address: &Pubkey,
+    program_id: &Pubkey,
+    state: &StateWithExtensions<TokenAccount>,
+) -> Result<()> {
+    let account = &state.base;
+    let decimals = fetch_mint(config, &account.mint)?.decimals;
+    let delegate: Option<Pubkey> = account.delegate.into();
+    let close_authority: Option<Pubkey> = account.close_authority.into();
+    let native: Option<u64> = account.is_native.into();
+    let associated = *address
+        == get_associated_token_address_with_program_id(&account.owner, &account.mint, program_id);
+    let account_state = account_state_name(account.state);
+
+    status!("💼 Token account ({})", token_program_name(program_id));
+    status!("   mint: {}", account.mint);
+    status!("   owner: {}", account.owner);
+    status!("   balance: {}", format_amount(account.amount, decimals));
+    status!("   state: {}", account_state);
+    status!(
+        "   associated token account: {}",
+        if associated { "yes" } else { "no" }
+    );
+    if let Some(delegate) = delegate {
+        status!(
+            "   delegate: {} ({})",
+            delegate,
+            format_amount(account.delegated_amount, decimals)
+        );
+    }
+    if let Some(close_authority) = close_authority {
+        status!("   close authority: {}", close_authority);
+    }
+    if let Some(reserve) = native {
+        status!("   wrapped SOL, rent reserve {}", Sol(reserve));
+    }
+
+    output::set("account", address.to_string());
+    output::set("program_id", program_id.to_string());
+    output::set("mint", account.mint.to_string());
+    output::set("token_owner", account.owner.to_string());
+    output::set("amount", account.amount.to_string());
+    output::set("ui_amount", format_amount(account.amount, decimals));
+    output::set("decimals", decimals);
+    output::set("state", account_state);
+    output::set("associated", associated);
+    output::set("delegate", delegate.map(|key| key.to_string()));
+    output::set("delegated_amount", account.delegated_amount.to_string());
+    output::set(
+        "close_authority",
+        close_authority.map(|key| key.to_string()),
+    );
+    output::set("native_rent_reserve", native);
+    output::set("extensions", extensions(state)?);
+
+    Ok(())
+}
+
+/// Prints every Token-2022 extension of a mint or token account and returns them as JSON.
+fn extensions<S: BaseState + Pack>(state: &StateWithExtensions<S>) -> Result<Vec<Value>> {
+    let types = state.get_extension_types()?;
+    let mut decoded = Vec::with_capacity(types.len());
+
+    for extension_type in types {
+        let fields: Vec<(&str, Value)> = match extension_type {
+            ExtensionType::Uninitialized => continue,
+            ExtensionType::TransferFeeConfig => {
+                let config = state.get_extension::<TransferFeeConfig>()?;
+                let newer = &config.newer_transfer_fee;
+                let older = &config.older_transfer_fee;
+                vec![
+                    ("authority", key(config.transfer_fee_config_authority)),
+                    (
+                        "withdraw_withheld_authority",
+                        key(config.withdraw_withheld_authority),
+                    ),
+                    ("withheld_amount", u64::from(config.withheld_amount).into()),
+                    (
+                        "transfer_fee_basis_points",
+                        u16::from(newer.transfer_fee_basis_points).into(),
+                    ),
+                    ("maximum_fee", u64::from(newer.maximum_fee).into()),
+                    ("epoch", u64::from(newer.epoch).into()),
+                    (
+                        "older_transfer_fee_basis_points",
+                        u16::from(older.transfer_fee_basis_points).into(),
+                    ),
+                    ("older_maximum_fee", u64::from(older.maximum_fee).into()),
+                ]
+            }
+            ExtensionType::TransferFeeAmount => {
+                let amount = state.get_extension::<TransferFeeAmount>()?;
+                vec![("withheld_amount", u64::from(amount.withheld_amount).into())]
+            }
+            ExtensionType::MintCloseAuthority => {
+                let extension = state.get_extension::<MintCloseAuthority>()?;
+                vec![("close_authority", key(extension.close_authority))]
+            }
+            ExtensionType::DefaultAccountState => {
+                let extension = state.get_extension::<DefaultAccountState>()?;
+                let state =
+                    AccountState::try_from(extension.state).map_or("unknown", account_state_name);
+                vec![("state", state.into())]
+            }
+            ExtensionType::InterestBearingConfig => {
+                let config = state.get_extension::<InterestBearingConfig>()?;
+                vec![
+                    ("rate_authority", key(config.rate_authority)),
+                    ("current_rate_bps", i16::from(config.current_rate).into()),
+                    (
+                        "pre_update_average_rate_bps",
+                        i16::from(config.pre_update_average_rate).into(),
+                    ),
+                    (
+                        "initialization_timestamp",
+                        i64::from(config.initialization_timestamp).into(),
+                    ),
+                    (
+                        "last_update_timestamp",
+                        i64::from(config.last_update_timestamp).into(),
+                    ),
+                ]
+            }
+            ExtensionType::PermanentDelegate => {
+                let extension = state.get_extension::<PermanentDelegate>()?;
+                vec![("delegate", key(extension.delegate))]
+            }
+            ExtensionType::TransferHook => {
+                let extension = state.get_extension::<TransferHook>()?;
+                vec![
+                    ("authority", key(extension.authority)),
+                    ("program_id", key(extension.program_id)),
+                ]
+            }
+            ExtensionType::TransferHookAccount => {
+                let extension = state.get_extension::<TransferHookAccount>()?;
+                vec![("transferring", bool::from(extension.transferring).into())]
+            }
+            ExtensionType::MetadataPointer => {
+                let extension = state.get_extension::<MetadataPointer>()?;
+                vec![
+                    ("authority", key(extension.authority)),
+                    ("metadata_address", key(extension.metadata_address)),
+                ]
+            }
+            ExtensionType::TokenMetadata => {
+                let metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+                vec![
+                    ("name", metadata.name.into()),
+                    ("symbol", metadata.symbol.into()),
+                ]
+            }
+            ExtensionType::GroupPointer => {
+                let extension = state.get_extension::<GroupPointer>()?;
+                vec![
+                    ("authority", key(extension.authority)),
+                    ("group_address", key(extension.group_address)),
+                ]
+            }
+            ExtensionType::TokenGroup => {
+                let group = state.get_extension::<TokenGroup>()?;
+                vec![
+                    ("update_authority", key(group.update_authority)),
+                    ("size", u64::from(group.size).into()),
+                    ("max_size", u64::from(group.max_size).into()),
+                ]
+            }
+            ExtensionType::GroupMemberPointer => {
+                let extension = state.get_extension::<GroupMemberPointer>()?;
+                vec![
+                    ("authority", key(extension.authority)),
+                    ("member_address", key(extension.member_address)),
+                ]
+            }
+            ExtensionType::TokenGroupMember => {
+                let member = state.get_extension::<TokenGroupMember>()?;
+                vec![
+                    ("group", member.group.to_string().into()),
+                    ("member_number", u64::from(member.member_number).into()),
+                ]
+            }
+            ExtensionType::MemoTransfer => {
+                let extension = state.get_extension::<MemoTransfer>()?;
+                vec![(
+                    "require_incoming_transfer_memos",
+                    bool::from(extension.require_incoming_transfer_memos).into(),
+                )]
+            }
+            ExtensionType::CpiGuard => {
+                let extension = state.get_extension::<CpiGuard>()?;
+                vec![("lock_cpi", bool::from(extension.lock_cpi).into())]
+            }
+            ExtensionType::ScaledUiAmount => {
+                let config = state.get_extension::<ScaledUiAmountConfig>()?;
+                vec![
+                    ("authority", key(config.authority)),
+                    ("multiplier", f64::from(config.multiplier).into()),
+                    ("new_multiplier", f64::from(config.new_multiplier).into()),
+                    (
+                        "new_multiplier_effective_timestamp",
+                        i64::from(config.new_multiplier_effective_timestamp).into(),
+                    ),
+                ]
+            }
+            ExtensionType::Pausable => {
+                let config = state.get_extension::<PausableConfig>()?;
+                vec![
+                    ("authority", key(config.authority)),
+                    ("paused", bool::from(config.paused).into()),
+                ]
+            }
+            ExtensionType::ConfidentialTransferMint
+            | ExtensionType::ConfidentialTransferAccount
+            | ExtensionType::ConfidentialTransferFeeConfig
+            | ExtensionType::ConfidentialTransferFeeAmount
+            | ExtensionType::ConfidentialMintBurn => {
+                // Зашифровані баланси без ключа власника не розшифрувати
+                vec![("note", "encrypted state is not decoded".into())]
+            }
+            // NonTransferable, ImmutableOwner та інші розширення-прапорці без полів
+            _ => vec![],
+        };
+
+        let name = format!("{:?}", extension_type);
+        status!("   🧩 {}", name);
+        let mut object = Map::new();
+        object.insert("type".to_string(), name.into());
+        for (field, value) in fields {
+            status!("      {}: {}", field, display(&value));
+            object.insert(field.to_string(), value);
+        }
+        decoded.push(Value::Object(object));
+    }
+
+    Ok(decoded)
+}
+
+fn inspect_loader_state(state: &UpgradeableLoaderState) -> &'static str {
+    match state {
+        UpgradeableLoaderState::Program {
+            programdata_address,
+        } => {
+            status!("⚙️  Upgradeable program");
+            status!("   program data: {}", programdata_address);
+            output::set("programdata_address", programdata_address.to_string());
+            "program"
+        }
+        UpgradeableLoaderState::ProgramData {
+            slot,
+            upgrade_authority_address,
+        } => {
+            status!("⚙️  Program data, deployed in slot {}", slot);
+            match upgrade_authority_address {
+                Some(authority) => status!("   upgrade authority: {}", authority),
+                None => status!("   upgrade authority: none (immutable)"),
+            }
+            output::set("deploy_slot", slot);
+            output::set(
+                "upgrade_authority",
+                upgrade_authority_address.map(|key| key.to_string()),
+            );
+            "program_data"
+        }
+        UpgradeableLoaderState::Buffer { authority_address } => {
+            status!("⚙️  Program buffer");
+            status!("   authority: {}", or_none(*authority_address));
+            output::set("authority", authority_address.map(|key| key.to_string()));
+            "buffer"
+        }
+        UpgradeableLoaderState::Uninitialized => {
+            status!("⚙️  Uninitialized loader account");
+            "unknown"
+        }
+    }
+}
+
+/// `Offer` and `Favorites` accounts of the Anchor programs in practice-3 and practice-4,
+/// recognized by their discriminator wherever the programs are deployed.
+fn inspect_anchor(config: &Config, account: &Account) -> Result<Option<&'static str>> {
+    let Some((discriminator, mut data)) = account.data.split_first_chunk::<8>() else {
+        return Ok(None);
+    };
+
+    if *discriminator == anchor_discriminator("Offer") {
+        let offer = Offer::decode(&mut data).context("Failed to decode escrow offer")?;
+        // Кількість показуємо з десятковими знаками мінта, якщо він ще існує
+        let wanted = match fetch_mint(config, &offer.token_mint_b) {
+            Ok(mint) => format_amount(offer.token_b_wanted_amount, mint.decimals),
+            Err(_) => offer.token_b_wanted_amount.to_string(),
+        };
+        status!("🤝 Escrow offer #{}", offer.id);
+        status!("   maker: {}", offer.maker);
+        status!("   offered mint (A): {}", offer.token_mint_a);
+        status!("   wanted mint (B): {}", offer.token_mint_b);
+        status!("   wanted amount: {}", wanted);
+        status!("   bump: {}", offer.bump);
+        output::set(
+            "offer",
+            json!({
+                "id": offer.id,
+                "maker": offer.maker.to_string(),
+                "token_mint_a": offer.token_mint_a.to_string(),
+                "token_mint_b": offer.token_mint_b.to_string(),
+                "token_b_wanted_amount": offer.token_b_wanted_amount.to_string(),
+                "bump": offer.bump,
+            }),
+        );
+        return Ok(Some("offer"));
+    }
+
+    if *discriminator == anchor_discriminator("Favorites") {
+        let number = u64::deserialize(&mut data).context("Failed to decode favorites")?;
+        let color = String::deserialize(&mut data).context("Failed to decode favorites")?;
+        // Версія з practice-4 додала authority, у practice-3 далі лише нулі
+        let authority = match account.owner {
+            FAVORITES_PROGRAM_ID => None,
+            _ => Option::<Pubkey>::deserialize(&mut data).ok().flatten(),
+        };
+        status!("⭐ Favorites");
+        status!("   number: {}", number);
+        status!("   color: {}", color);
+        if let Some(authority) = authority {
+            status!("   authority: {}", authority);
+        }
+        output::set(
+            "favorites",
+            json!({
+                "number": number,
+                "color": color,
+                "authority": authority.map(|key| key.to_string()),
+            }),
+        );
+        return Ok(Some("favorites"));
+    }
+
+    Ok(None)
+}
+
+/// First 8 bytes of sha256("account:<Name>"), the prefix Anchor writes into every account.
+fn anchor_discriminator(name: &str) -> [u8; 8] {
+    let hash = hashv(&[b"account:", name.as_bytes()]).to_bytes();
+    let mut discriminator = [0; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+fn program_name(program_id: &Pubkey) -> Option<&'static str> {
+    let name = match *program_id {
+        id if id == system_program::id() => "System Program",
+        id if id == spl_token::id() => "SPL Token",
+        id if id == spl_token_2022::id() => "Token-2022",
+        id if id == spl_associated_token_account::id() => "Associated Token Account",
+        id if id == spl_memo::id() => "Memo",
+        id if id == solana_address_lookup_table_interface::program::id() => "Address Lookup Table",
+        id if id == bpf_loader_upgradeable::id() => "BPF Upgradeable Loader",
+        METAPLEX_PROGRAM_ID => "Metaplex Token Metadata",
+        ESCROW_PROGRAM_ID => "Escrow",
+        FAVORITES_PROGRAM_ID | FAVORITES_2_PROGRAM_ID => "Favorites",
+        _ => return None,
+    };
+    Some(name)
+}
+
+fn token_program_name(program_id: &Pubkey) -> &'static str {
+    if *program_id == spl_token::id() {
+        "SPL Token"
+    } else {
+        "Token-2022"
+    }
+}
+
+fn account_state_name(state: AccountState) -> &'static str {
+    match state {
+        AccountState::Uninitialized => "uninitialized",
+        AccountState::Initialized => "initialized",
+        AccountState::Frozen => "frozen",
+    }
+}
+
+fn key(key: impl Into<Option<Pubkey>>) -> Value {
+    key.into().map_or(Value::Null, |key| key.to_string().into())
+}
+
+fn or_none(key: Option<Pubkey>) -> String {
+    key.map_or_else(|| "none".to_string(), |key| key.to_string())
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => "none".to_string(),
+        value => value.to_string(),
+    }
+}
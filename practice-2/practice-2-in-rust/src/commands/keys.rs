@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Args, Subcommand, ValueEnum};
+use serde_json::json;
+use solana_sdk::signer::Signer;
+
+use crate::{keystore, output, signer::SignerSource};
+
+#[derive(Subcommand)]
+pub enum KeysCommand {
+    /// Encrypt a keypair with a passphrase and store it in the keystore
+    Import(ImportKeyArgs),
+
+    /// Decrypt a stored keypair and print or save it
+    Export(ExportKeyArgs),
+
+    /// List stored keys and their public keys
+    List,
+}
+
+#[derive(Args)]
+pub struct ImportKeyArgs {
+    /// Name of the key, use it later as `--keypair keystore:NAME`
+    pub name: String,
+
+    /// Keypair to import: keypair file, env:VAR, base58:SECRET or prompt
+    #[arg(long, default_value = "prompt")]
+    pub from: SignerSource,
+
+    /// Overwrite an existing key with the same name
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// JSON byte array, compatible with solana-keygen
+    Json,
+    /// Base58 secret, compatible with Phantom
+    Base58,
+}
+
+#[derive(Args)]
+pub struct ExportKeyArgs {
+    /// Name of the stored key
+    pub name: String,
+
+    /// Output format of the secret key
+    #[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+    pub format: ExportFormat,
+
+    /// Write the secret into this file instead of stdout
+    #[arg(long)]
+    pub outfile: Option<PathBuf>,
+}
+
+pub fn run(command: KeysCommand) -> Result<()> {
+    match command {
+        KeysCommand::Import(args) => import(args),
+        KeysCommand::Export(args) => export(args),
+        KeysCommand::List => list(),
+    }
+}
+
+fn import(args: ImportKeyArgs) -> Result<()> {
+    let keypair = args.from.load("imported")?;
+    let passphrase = keystore::read_passphrase("🔐 New passphrase: ", true)?;
+
+    let file = keystore::encrypt(&keypair, &passphrase)?;
+    let path = keystore::save(&args.name, &file, args.force)?;
+
+    output::set("name", &args.name);
+    output::set("pubkey", keypair.pubkey().to_string());
+    output::set("file", &path);
+
+    status!("✅ Key '{}' imported: {}", args.name, keypair.pubkey());
+    status!("📁 {}", path.display());
+    status!("Use it with: --keypair keystore:{}", args.name);
+
+    Ok(())
+}
+
+fn export(args: ExportKeyArgs) -> Result<()> {
+    let keypair = keystore::load(&args.name)?;
+
+    let secret = match args.format {
+        ExportFormat::Json => serde_json::to_string(&keypair.to_bytes().to_vec())?,
+        ExportFormat::Base58 => keypair.to_base58_string(),
+    };
+
+    output::set("name", &args.name);
+    output::set("pubkey", keypair.pubkey().to_string());
+    match args.outfile {
+        Some(path) => {
+            output::set("file", &path);
+            keystore::write_private(&path, secret.as_bytes())?;
+            status!("✅ Key '{}' exported to {}", args.name, path.display());
+        }
+        // У JSON-режимі секрет потрапляє лише в результат, а не ще й у stderr
+        None if output::is_json() => output::set("secret", &secret),
+        None => status!("{}", secret),
+    }
+
+    Ok(())
+}
+
+fn list() -> Result<()> {
+    let keys = keystore::list()?;
+    output::set(
+        "keys",
+        keys.iter()
+            .map(|(name, pubkey)| json!({ "name": name, "pubkey": pubkey.to_string() }))
+            .collect::<Vec<_>>(),
+    );
+    if keys.is_empty() {
+        status!(
+            "📭 Keystore is empty ({})",
+            keystore::keystore_dir()?.display()
+        );
+        return Ok(());
+    }
+
+    for (name, pubkey) in keys {
+        status!("{:<20} {}", name, pubkey);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,483 @@
+use anyhow::{bail, Context, Result};
+use clap::{Args, Subcommand};
+use solana_address_lookup_table_interface::{
+    instruction as lookup_table_instruction,
+    state::{
+        estimate_last_valid_slot, AddressLookupTable, LookupTableMeta, LOOKUP_TABLE_MAX_ADDRESSES,
+    },
+};
+use solana_sdk::{
+    clock::Slot, commitment_config::CommitmentConfig, instruction::Instruction, pubkey::Pubkey,
+    signature::Signer,
+};
+
+use crate::{config::Config, output, signer::SignerSource, transaction};
+
+/// Addresses per ExtendLookupTable instruction, 32 bytes each still leave room for the
+/// compute budget and nonce instructions in one transaction.
+const MAX_ADDRESSES_PER_EXTEND: usize = 20;
+
+#[derive(Subcommand)]
+pub enum LookupTableCommand {
+    /// Create an address lookup table, optionally filled with addresses
+    Create(CreateLookupTableArgs),
+
+    /// Append addresses to a lookup table
+    Extend(ExtendLookupTableArgs),
+
+    /// Start deactivating a lookup table so that it can be closed (irreversible)
+    Deactivate(DeactivateLookupTableArgs),
+
+    /// Close a deactivated lookup table and reclaim its rent
+    Close(CloseLookupTableArgs),
+
+    /// Print the authority, status and addresses of a lookup table
+    Show(ShowLookupTableArgs),
+}
+
+#[derive(Args)]
+pub struct CreateLookupTableArgs {
+    /// Addresses to store in the new table
+    pub addresses: Vec<Pubkey>,
+
+    /// Lookup table authority signer [default: --keypair]
+    #[arg(long)]
+    pub authority: Option<SignerSource>,
+
+    /// Recent slot to derive the table address from, needed offline [default: latest finalized]
+    #[arg(long)]
+    pub recent_slot: Option<Slot>,
+}
+
+#[derive(Args)]
+pub struct ExtendLookupTableArgs {
+    /// Lookup table address
+    pub address: Pubkey,
+
+    /// Addresses to append
+    #[arg(required = true)]
+    pub addresses: Vec<Pubkey>,
+
+    /// Lookup table authority signer [default: --keypair]
+    #[arg(long)]
+    pub authority: Option<SignerSource>,
+}
+
+#[derive(Args)]
+pub struct DeactivateLookupTableArgs {
+    /// Lookup table address
+    pub address: Pubkey,
+
+    /// Lookup table authority signer [default: --keypair]
+    #[arg(long)]
+    pub authority: Option<SignerSource>,
+}
+
+#[derive(Args)]
+pub struct CloseLookupTableArgs {
+    /// Lookup table address
+    pub address: Pubkey,
+
+    /// Wallet that receives the rent [default: fee payer]
+    #[arg(long)]
+    pub recipient: Option<Pubkey>,
+
+    /// Lookup table authority signer [default: --keypair]
+    #[arg(long)]
+    pub authority: Option<SignerSource>,
+}
+
+#[derive(Args)]
+pub struct ShowLookupTableArgs {
+    /// Lookup table address
+    pub address: Pubkey,
+}
+
+pub fn run(config: &Config, command: LookupTableCommand) -> Result<()> {
+    match command {
+        LookupTableCommand::Create(args) => create(config, args),
+        LookupTableCommand::Extend(args) => extend(config, args),
+        LookupTableCommand::Deactivate(args) => deactivate(config, args),
+        LookupTableCommand::Close(args) => close(config, args),
+        LookupTableCommand::Show(args) => show(config, args),
+    }
+}
+
+fn create(config: &Config, args: CreateLookupTableArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    let authority = config.signer_or_default(args.authority.as_ref(), "lookup table authority")?;
+    check_capacity(0, args.addresses.len())?;
+    check_chunks(config, args.addresses.len())?;
+
+    // Адреса таблиці виводиться з недавнього слота, тому беремо finalized
+    let recent_slot = match args.recent_slot {
+        Some(slot) => slot,
+        None => config
+            .rpc
+            .get_slot_with_commitment(CommitmentConfig::finalized())
+            .context("Failed to get a recent slot")?,
+    };
+    let mut chunks = args.addresses.chunks(MAX_ADDRESSES_PER_EXTEND);
+    let (address, instructions) = create_instructions(
+        &authority.pubkey(),
+        &payer.pubkey(),
+        recent_slot,
+        chunks.next(),
+    );
+
+    status!("🔑 Payer: {}", payer.pubkey());
+    output::set("lookup_table", address.to_string());
+    output::set("authority", authority.pubkey().to_string());
+
+    let signers = required_signers(&instructions, &[authority.as_ref()]);
+    let Some(signature) = transaction::process(config, &payer, &signers, &instructions)? else {
+        return Ok(());
+    };
+    status!("✅ Lookup table created: {}", address);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+    output::push("signatures", signature.to_string());
+
+    for chunk in chunks {
+        send_extend(config, &payer, authority.as_ref(), &address, chunk)?;
+    }
+    output::set("addresses", &args.addresses);
+    if !args.addresses.is_empty() {
+        status!("📇 Stored {} addresses", args.addresses.len());
+    }
+    status!("Build v0 transactions with --lookup-table {}", address);
+
+    Ok(())
+}
+
+fn extend(config: &Config, args: ExtendLookupTableArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    let authority = config.signer_or_default(args.authority.as_ref(), "lookup table authority")?;
+
+    let mut addresses = args.addresses;
+    if !config.transaction.offline() {
+        let (meta, stored) = fetch_table(config, &args.address)?;
+        check_authority(&args.address, &meta, &authority.pubkey())?;
+        if meta.deactivation_slot != Slot::MAX {
+            bail!(
+                "Lookup table {} is deactivated and cannot be extended",
+                args.address
+            );
+        }
+
+        let mut unique: Vec<Pubkey> = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            if stored.contains(&address) || unique.contains(&address) {
+                status!("ℹ️  {} is already in the table, skipping", address);
+            } else {
+                unique.push(address);
+            }
+        }
+        if unique.is_empty() {
+            status!("✅ Nothing to add, every address is already in the table");
+            return Ok(());
+        }
+        check_capacity(stored.len(), unique.len())?;
+        addresses = unique;
+    }
+    check_chunks(config, addresses.len())?;
+
+    output::set("lookup_table", args.address.to_string());
+    output::set("addresses", &addresses);
+    for chunk in addresses.chunks(MAX_ADDRESSES_PER_EXTEND) {
+        if !send_extend(config, &payer, authority.as_ref(), &args.address, chunk)? {
+            return Ok(());
+        }
+    }
+    status!("✅ Added {} addresses to {}", addresses.len(), args.address);
+
+    Ok(())
+}
+
+fn deactivate(config: &Config, args: DeactivateLookupTableArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    let authority = config.signer_or_default(args.authority.as_ref(), "lookup table authority")?;
+    if !config.transaction.offline() {
+        let (meta, _) = fetch_table(config, &args.address)?;
+        check_authority(&args.address, &meta, &authority.pubkey())?;
+        if meta.deactivation_slot != Slot::MAX {
+            bail!(
+                "Lookup table {} was already deactivated in slot {}",
+                args.address,
+                meta.deactivation_slot
+            );
+        }
+    }
+
+    let instruction =
+        lookup_table_instruction::deactivate_lookup_table(args.address, authority.pubkey());
+    output::set("lookup_table", args.address.to_string());
+
+    let Some(signature) = transaction::process(config, &payer, &[&authority], &[instruction])?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Lookup table deactivated: {}", args.address);
+    status!("   it can be closed in about {} slots", slot_hashes_len());
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
+
+fn close(config: &Config, args: CloseLookupTableArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    let authority = config.signer_or_default(args.authority.as_ref(), "lookup table authority")?;
+    let recipient = args.recipient.unwrap_or_else(|| payer.pubkey());
+
+    if !config.transaction.offline() {
+        let (meta, _) = fetch_table(config, &args.address)?;
+        check_authority(&args.address, &meta, &authority.pubkey())?;
+        if meta.deactivation_slot == Slot::MAX {
+            bail!(
+                "Lookup table {} is still active, run `spl lookup-table deactivate` first",
+                args.address
+            );
+        }
+        let slot = config
+            .rpc
+            .get_slot_with_commitment(config.commitment)
+            .context("Failed to get the current slot")?;
+        let closable = estimate_last_valid_slot(meta.deactivation_slot);
+        if slot <= closable {
+            bail!(
+                "Lookup table {} is still deactivating, it can be closed after slot {} (now {})",
+                args.address,
+                closable,
+                slot
+            );
+        }
+    }
+
+    let instruction =
+        lookup_table_instruction::close_lookup_table(args.address, authority.pubkey(), recipient);
+    output::set("lookup_table", args.address.to_string());
+    output::set("recipient", recipient.to_string());
+
+    let Some(signature) = transaction::process(config, &payer, &[&authority], &[instruction])?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Lookup table closed, rent sent to {}", recipient);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
+
+pub fn show(config: &Config, args: ShowLookupTableArgs) -> Result<()> {
+    let (meta, addresses) = fetch_table(config, &args.address)?;
+    let slot = config
+        .rpc
+        .get_slot_with_commitment(config.commitment)
+        .context("Failed to get the current slot")?;
+
+    let status = if meta.deactivation_slot == Slot::MAX {
+        "active"
+    } else if slot <= estimate_last_valid_slot(meta.deactivation_slot) {
+        "deactivating"
+    } else {
+        "deactivated"
+    };
+
+    output::set("lookup_table", args.address.to_string());
+    output::set(
+        "authority",
+        meta.authority.map(|authority| authority.to_string()),
+    );
+    output::set("status", status);
+    output::set(
+        "deactivation_slot",
+        (meta.deactivation_slot != Slot::MAX).then_some(meta.deactivation_slot),
+    );
+    output::set("last_extended_slot", meta.last_extended_slot);
+    output::set("addresses", &addresses);
+
+    status!("📇 Lookup table {}", args.address);
+    match meta.authority {
+        Some(authority) => status!("   authority: {}", authority),
+        None => status!("   authority: none (frozen)"),
+    }
+    match status {
+        "active" => status!("   status: active"),
+        _ => status!(
+            "   status: {} since slot {}",
+            status,
+            meta.deactivation_slot
+        ),
+    }
+    status!("   last extended in slot {}", meta.last_extended_slot);
+    status!(
+        "   addresses: {}/{}",
+        addresses.len(),
+        LOOKUP_TABLE_MAX_ADDRESSES
+    );
+    for (index, address) in addresses.iter().enumerate() {
+        status!("   {:>3}: {}", index, address);
+    }
+
+    Ok(())
+}
+
+/// Sends one ExtendLookupTable transaction, `false` when it was only signed or simulated.
+fn send_extend(
+    config: &Config,
+    payer: &dyn Signer,
+    authority: &dyn Signer,
+    address: &Pubkey,
+    chunk: &[Pubkey],
+) -> Result<bool> {
+    let instruction = extend_instruction(address, &authority.pubkey(), &payer.pubkey(), chunk);
+    let Some(signature) = transaction::process(config, payer, &[authority], &[instruction])? else {
+        return Ok(false);
+    };
+    status!(
+        "➕ Added {} addresses, signature: {}",
+        chunk.len(),
+        signature
+    );
+    output::push("signatures", signature.to_string());
+    Ok(true)
+}
+
+/// CreateLookupTable and, if given, the first ExtendLookupTable chunk. The create instruction
+/// does not need the authority's signature, only the extend does.
+fn create_instructions(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    recent_slot: Slot,
+    first_chunk: Option<&[Pubkey]>,
+) -> (Pubkey, Vec<Instruction>) {
+    let (instruction, address) =
+        lookup_table_instruction::create_lookup_table(*authority, *payer, recent_slot);
+    let mut instructions = vec![instruction];
+    if let Some(chunk) = first_chunk {
+        instructions.push(extend_instruction(&address, authority, payer, chunk));
+    }
+    (address, instructions)
+}
+
+/// Keeps the `signers` that one of the `instructions` actually asks a signature from.
+fn required_signers<'a>(
+    instructions: &[Instruction],
+    signers: &[&'a dyn Signer],
+) -> Vec<&'a dyn Signer> {
+    signers
+        .iter()
+        .copied()
+        .filter(|signer| {
+            instructions.iter().any(|instruction| {
+                instruction
+                    .accounts
+                    .iter()
+                    .any(|meta| meta.is_signer && meta.pubkey == signer.pubkey())
+            })
+        })
+        .collect()
+}
+
+fn extend_instruction(
+    address: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    addresses: &[Pubkey],
+) -> Instruction {
+    lookup_table_instruction::extend_lookup_table(
+        *address,
+        *authority,
+        Some(*payer),
+        addresses.to_vec(),
+    )
+}
+
+/// Reads a lookup table: its metadata and stored addresses.
+fn fetch_table(config: &Config, address: &Pubkey) -> Result<(LookupTableMeta, Vec<Pubkey>)> {
+    let account = config
+        .rpc
+        .get_account_with_commitment(address, config.commitment)
+        .with_context(|| format!("Failed to fetch lookup table {}", address))?
+        .value
+        .with_context(|| format!("Lookup table {} does not exist", address))?;
+    let table = AddressLookupTable::deserialize(&account.data)
+        .with_context(|| format!("{} is not an address lookup table", address))?;
+    Ok((table.meta, table.addresses.to_vec()))
+}
+
+fn check_authority(address: &Pubkey, meta: &LookupTableMeta, authority: &Pubkey) -> Result<()> {
+    match meta.authority {
+        Some(current) if current == *authority => Ok(()),
+        Some(current) => bail!(
+            "Authority of lookup table {} is {}, not {}",
+            address,
+            current,
+            authority
+        ),
+        None => bail!("Lookup table {} is frozen and has no authority", address),
+    }
+}
+
+fn check_capacity(stored: usize, added: usize) -> Result<()> {
+    if stored + added > LOOKUP_TABLE_MAX_ADDRESSES {
+        bail!(
+            "A lookup table holds at most {} addresses, {} stored + {} new",
+            LOOKUP_TABLE_MAX_ADDRESSES,
+            stored,
+            added
+        );
+    }
+    Ok(())
+}
+
+/// `--sign-only` exports a single transaction, so the addresses must fit into one.
+fn check_chunks(config: &Config, addresses: usize) -> Result<()> {
+    if config.transaction.sign_only && addresses > MAX_ADDRESSES_PER_EXTEND {
+        bail!(
+            "--sign-only can add at most {} addresses per transaction, split them into several `extend` calls",
+            MAX_ADDRESSES_PER_EXTEND
+        );
+    }
+    Ok(())
+}
+
+/// Slots a deactivated table stays usable, the length of the SlotHashes sysvar.
+fn slot_hashes_len() -> Slot {
+    estimate_last_valid_slot(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{hash::Hash, signature::Keypair};
+
+    use super::*;
+
+    fn sign(payer: &Keypair, authority: &Keypair, instructions: &[Instruction]) -> Result<()> {
+        let message = transaction::compile(&payer.pubkey(), instructions, &[], Hash::default())?;
+        let mut tx = transaction::unsigned(message);
+        let signers = required_signers(instructions, &[authority as &dyn Signer]);
+        transaction::partial_sign(&mut tx, &[&[payer as &dyn Signer], &signers[..]].concat())?;
+        assert!(transaction::missing_signers(&tx).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn create_without_addresses_needs_no_authority_signature() {
+        let (payer, authority) = (Keypair::new(), Keypair::new());
+        let (_, instructions) = create_instructions(&authority.pubkey(), &payer.pubkey(), 42, None);
+        assert!(required_signers(&instructions, &[&authority]).is_empty());
+        sign(&payer, &authority, &instructions).unwrap();
+    }
+
+    #[test]
+    fn create_with_addresses_is_signed_by_the_authority() {
+        let (payer, authority) = (Keypair::new(), Keypair::new());
+        let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let (_, instructions) =
+            create_instructions(&authority.pubkey(), &payer.pubkey(), 42, Some(&addresses));
+        assert_eq!(required_signers(&instructions, &[&authority]).len(), 1);
+        sign(&payer, &authority, &instructions).unwrap();
+    }
+}
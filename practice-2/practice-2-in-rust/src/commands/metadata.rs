@@ -0,0 +1,766 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{ArgGroup, Args, Subcommand};
+use serde_json::{json, Map, Value};
+use solana_sdk::{account::Account, instruction::Instruction, pubkey::Pubkey, signature::Signer};
+use solana_system_interface::instruction as system_instruction;
+use spl_token_2022::{
+    extension::{metadata_pointer::MetadataPointer, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+use spl_token_metadata_interface::{
+    instruction::{initialize, remove_key, update_authority, update_field},
+    state::{Field, TokenMetadata},
+};
+
+use crate::{config::Config, metaplex, output, signer::SignerSource, transaction};
+
+#[derive(Subcommand)]
+pub enum MetadataCommand {
+    /// Initialize metadata: inside Token-2022 mints, in a Metaplex account for classic SPL Token mints
+    Init(InitMetadataArgs),
+
+    /// Change name, symbol, uri or additional fields
+    Update(UpdateMetadataArgs),
+
+    /// Rotate or revoke the metadata update authority
+    Authority(MetadataAuthorityArgs),
+
+    /// Print the metadata currently stored in the mint
+    Show(ShowMetadataArgs),
+}
+
+#[derive(Args)]
+pub struct InitMetadataArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Token name
+    #[arg(long)]
+    pub name: String,
+
+    /// Token symbol
+    #[arg(long)]
+    pub symbol: String,
+
+    /// URI of the off-chain JSON metadata
+    #[arg(long)]
+    pub uri: String,
+
+    /// Additional metadata field KEY=VALUE (repeatable, Token-2022 only)
+    #[arg(long = "field", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    pub fields: Vec<(String, String)>,
+
+    /// Mint authority signer, also the update authority [default: --keypair]
+    #[arg(long)]
+    pub mint_authority: Option<SignerSource>,
+}
+
+#[derive(Args)]
+pub struct UpdateMetadataArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// New token name
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// New token symbol
+    #[arg(long)]
+    pub symbol: Option<String>,
+
+    /// New URI of the off-chain JSON metadata
+    #[arg(long)]
+    pub uri: Option<String>,
+
+    /// Set an additional field KEY=VALUE (repeatable)
+    #[arg(long = "field", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    pub fields: Vec<(String, String)>,
+
+    /// Remove an additional field (repeatable)
+    #[arg(long = "remove-field", value_name = "KEY")]
+    pub remove_fields: Vec<String>,
+
+    /// JSON object of changes, e.g. {"uri": "...", "level": "3", "old_key": null}; null removes a field
+    #[arg(long)]
+    pub from_file: Option<PathBuf>,
+
+    /// Metadata update authority signer [default: --keypair]
+    #[arg(long)]
+    pub update_authority: Option<SignerSource>,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("target").required(true).args(["new_authority", "revoke"])))]
+pub struct MetadataAuthorityArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// New update authority
+    #[arg(long)]
+    pub new_authority: Option<Pubkey>,
+
+    /// Remove the update authority, making the metadata immutable
+    #[arg(long)]
+    pub revoke: bool,
+
+    /// Current update authority signer [default: --keypair]
+    #[arg(long)]
+    pub update_authority: Option<SignerSource>,
+}
+
+#[derive(Args)]
+pub struct ShowMetadataArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+}
+
+/// One change of the metadata, applied in the given order.
+enum MetadataChange {
+    Set(Field, String),
+    Remove(String),
+}
+
+/// Token program that owns a mint and so decides where its metadata lives.
+enum MintProgram {
+    /// Classic SPL Token: metadata in a Metaplex PDA
+    Token,
+    /// Token-2022: metadata in the TokenMetadata extension of the mint
+    Token2022,
+}
+
+fn mint_program(config: &Config, mint: &Pubkey) -> Result<MintProgram> {
+    let owner = config
+        .rpc
+        .get_account_with_commitment(mint, config.commitment)?
+        .value
+        .ok_or_else(|| anyhow!("Mint account {} not found", mint))?
+        .owner;
+
+    output::set("mint", mint.to_string());
+    output::set("program_id", owner.to_string());
+    if owner == spl_token::id() {
+        Ok(MintProgram::Token)
+    } else if owner == spl_token_2022::id() {
+        Ok(MintProgram::Token2022)
+    } else {
+        bail!(
+            "{} is not an SPL Token or Token-2022 mint, its owner is {}",
+            mint,
+            owner
+        )
+    }
+}
+
+pub fn run(config: &Config, command: MetadataCommand) -> Result<()> {
+    match command {
+        MetadataCommand::Init(args) => match mint_program(config, &args.mint)? {
+            MintProgram::Token => init_metaplex(config, args),
+            MintProgram::Token2022 => init(config, args),
+        },
+        MetadataCommand::Update(args) => match mint_program(config, &args.mint)? {
+            MintProgram::Token => update_metaplex(config, args),
+            MintProgram::Token2022 => update(config, args),
+        },
+        MetadataCommand::Authority(args) => match mint_program(config, &args.mint)? {
+            MintProgram::Token => authority_metaplex(config, args),
+            MintProgram::Token2022 => authority(config, args),
+        },
+        MetadataCommand::Show(args) => match mint_program(config, &args.mint)? {
+            MintProgram::Token => show_metaplex(config, args),
+            MintProgram::Token2022 => show(config, args),
+        },
+    }
+}
+
+fn parse_key_value(value: &str) -> Result<(String, String), String> {
+    value
+        .split_once('=')
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{}'", value))
+}
+
+fn parse_field(field: &str) -> Field {
+    match field {
+        "name" => Field::Name,
+        "symbol" => Field::Symbol,
+        "uri" => Field::Uri,
+        key => Field::Key(key.to_string()),
+    }
+}
+
+/// Token-2022 mint account and the metadata embedded in it, if any.
+pub struct MintWithMetadata {
+    pub account: Account,
+    pub metadata: Option<TokenMetadata>,
+}
+
+pub fn fetch_mint_metadata(config: &Config, mint: &Pubkey) -> Result<MintWithMetadata> {
+    let account = config
+        .rpc
+        .get_account_with_commitment(mint, config.commitment)?
+        .value
+        .ok_or_else(|| anyhow!("Mint account {} not found", mint))?;
+    if account.owner != spl_token_2022::id() {
+        bail!("Mint {} is not owned by the Token-2022 program", mint);
+    }
+
+    let state = StateWithExtensions::<Mint>::unpack(&account.data)
+        .with_context(|| format!("Account {} is not a mint", mint))?;
+
+    // Метадані зберігаються в самому мінті, тому MetadataPointer має вказувати на нього
+    let pointer = state
+        .get_extension::<MetadataPointer>()
+        .map_err(|_| anyhow!("Mint {} has no MetadataPointer extension", mint))?;
+    let metadata_address: Option<Pubkey> = pointer.metadata_address.into();
+    if metadata_address != Some(*mint) {
+        bail!(
+            "MetadataPointer of {} points to {:?}, not to the mint itself",
+            mint,
+            metadata_address
+        );
+    }
+
+    let metadata = state.get_variable_len_extension::<TokenMetadata>().ok();
+    Ok(MintWithMetadata { account, metadata })
+}
+
+/// Transfer that keeps the mint rent exempt once its metadata entry grows to `metadata_size` bytes.
+/// Token-2022 reallocates the account itself but never pays for the extra bytes.
+pub fn rent_top_up(
+    config: &Config,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    current: &MintWithMetadata,
+    metadata_size: usize,
+) -> Result<Option<Instruction>> {
+    let old_size = match &current.metadata {
+        Some(metadata) => metadata.tlv_size_of()?,
+        None => 0,
+    };
+    let new_len = current.account.data.len() - old_size + metadata_size;
+    let required = config.rpc.get_minimum_balance_for_rent_exemption(new_len)?;
+
+    Ok(required
+        .checked_sub(current.account.lamports)
+        .filter(|lamports| *lamports > 0)
+        .map(|lamports| system_instruction::transfer(payer, mint, lamports)))
+}
+
+pub fn print_metadata(metadata: &TokenMetadata) {
+    let update_authority: Option<Pubkey> = metadata.update_authority.into();
+    output::set(
+        "metadata",
+        json!({
+            "name": metadata.name,
+            "symbol": metadata.symbol,
+            "uri": metadata.uri,
+            "update_authority": update_authority.map(|authority| authority.to_string()),
+            "additional_metadata": metadata
+                .additional_metadata
+                .iter()
+                .map(|(key, value)| (key.clone(), Value::from(value.as_str())))
+                .collect::<Map<String, Value>>(),
+        }),
+    );
+    status!("📄 Token metadata of {}", metadata.mint);
+    status!("   name: {}", metadata.name);
+    status!("   symbol: {}", metadata.symbol);
+    status!("   uri: {}", metadata.uri);
+    match update_authority {
+        Some(authority) => status!("   update authority: {}", authority),
+        None => status!("   update authority: none (immutable)"),
+    }
+    for (key, value) in &metadata.additional_metadata {
+        status!("   {}: {}", key, value);
+    }
+}
+
+fn init(config: &Config, args: InitMetadataArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    let signer = config.signer_or_default(args.mint_authority.as_ref(), "mint authority")?;
+    let signer_pubkey = signer.pubkey();
+    status!("🔑 Signer: {}", signer_pubkey);
+
+    let current = fetch_mint_metadata(config, &args.mint)?;
+    if current.metadata.is_some() {
+        bail!(
+            "Metadata of {} is already initialized, use `metadata update`",
+            args.mint
+        );
+    }
+
+    let mut new_metadata = TokenMetadata {
+        update_authority: Some(signer_pubkey).try_into()?,
+        mint: args.mint,
+        name: args.name.clone(),
+        symbol: args.symbol.clone(),
+        uri: args.uri.clone(),
+        additional_metadata: vec![],
+    };
+    for (key, value) in &args.fields {
+        new_metadata.set_key_value(key.clone(), value.clone());
+    }
+
+    let mut instructions = vec![];
+    if let Some(top_up) = rent_top_up(
+        config,
+        &payer.pubkey(),
+        &args.mint,
+        &current,
+        new_metadata.tlv_size_of()?,
+    )? {
+        instructions.push(top_up);
+    }
+
+    instructions.push(initialize(
+        &spl_token_2022::id(),
+        &args.mint,
+        &signer_pubkey,
+        &args.mint,
+        &signer_pubkey,
+        args.name,
+        args.symbol,
+        args.uri,
+    ));
+    let field_count = args.fields.len();
+    for (key, value) in args.fields {
+        instructions.push(update_field(
+            &spl_token_2022::id(),
+            &args.mint,
+            &signer_pubkey,
+            Field::Key(key),
+            value,
+        ));
+    }
+
+    // Довгий список полів не влазить в одну транзакцію, решту полів дописуємо наступними
+    let batches = pack_instructions(config, &payer.pubkey(), instructions)?;
+    if config.transaction.sign_only && batches.len() > 1 {
+        bail!(
+            "Metadata with {} fields needs {} transactions, --sign-only signs a single one: pass fewer --field and add the rest with `metadata update`",
+            field_count,
+            batches.len()
+        );
+    }
+    if !send_batches(config, &payer, &[&signer], &batches)? {
+        return Ok(());
+    }
+    status!("✅ Metadata initialized!");
+
+    // Перевірка: читаємо мінт назад
+    let stored = fetch_mint_metadata(config, &args.mint)?
+        .metadata
+        .ok_or_else(|| anyhow!("Metadata not found on {} after initialization", args.mint))?;
+    if stored != new_metadata {
+        bail!("Stored metadata does not match what was sent");
+    }
+    print_metadata(&stored);
+
+    Ok(())
+}
+
+/// Reads changes from a JSON object: string values set a field, `null` removes it.
+fn read_changes_file(path: &PathBuf) -> Result<Vec<MetadataChange>> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let object: Map<String, Value> = serde_json::from_str(&contents)
+        .with_context(|| format!("{} must contain a JSON object", path.display()))?;
+
+    object
+        .into_iter()
+        .map(|(key, value)| match value {
+            Value::String(value) => Ok(MetadataChange::Set(parse_field(&key), value)),
+            Value::Null => match parse_field(&key) {
+                Field::Key(key) => Ok(MetadataChange::Remove(key)),
+                _ => bail!("'{}' cannot be removed, only additional fields can", key),
+            },
+            other => bail!("Value of '{}' must be a string or null, got {}", key, other),
+        })
+        .collect()
+}
+
+/// File changes go first so that flags passed on the command line win.
+fn collect_changes(args: &UpdateMetadataArgs) -> Result<Vec<MetadataChange>> {
+    let mut changes = match &args.from_file {
+        Some(path) => read_changes_file(path)?,
+        None => vec![],
+    };
+
+    let standard = [
+        (Field::Name, &args.name),
+        (Field::Symbol, &args.symbol),
+        (Field::Uri, &args.uri),
+    ];
+    for (field, value) in standard {
+        if let Some(value) = value {
+            changes.push(MetadataChange::Set(field, value.clone()));
+        }
+    }
+    for (key, value) in &args.fields {
+        changes.push(MetadataChange::Set(parse_field(key), value.clone()));
+    }
+    for key in &args.remove_fields {
+        changes.push(MetadataChange::Remove(key.clone()));
+    }
+
+    if changes.is_empty() {
+        bail!("Nothing to update: pass --name, --symbol, --uri, --field, --remove-field or --from-file");
+    }
+    Ok(changes)
+}
+
+fn field_value<'a>(metadata: &'a TokenMetadata, field: &Field) -> Option<&'a str> {
+    match field {
+        Field::Name => Some(&metadata.name),
+        Field::Symbol => Some(&metadata.symbol),
+        Field::Uri => Some(&metadata.uri),
+        Field::Key(key) => metadata
+            .additional_metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str()),
+    }
+}
+
+fn check_update_authority(metadata: &TokenMetadata, signer: &Pubkey) -> Result<()> {
+    let update_authority: Option<Pubkey> = metadata.update_authority.into();
+    match update_authority {
+        Some(authority) if authority == *signer => Ok(()),
+        Some(authority) => bail!(
+            "{} is not the update authority of {}, the authority is {}",
+            signer,
+            metadata.mint,
+            authority
+        ),
+        None => bail!("Metadata of {} is immutable", metadata.mint),
+    }
+}
+
+fn fetch_existing_metadata(
+    config: &Config,
+    mint: &Pubkey,
+) -> Result<(MintWithMetadata, TokenMetadata)> {
+    let current = fetch_mint_metadata(config, mint)?;
+    let metadata = current
+        .metadata
+        .clone()
+        .ok_or_else(|| anyhow!("Mint {} has no metadata, use `metadata init`", mint))?;
+    Ok((current, metadata))
+}
+
+fn update(config: &Config, args: UpdateMetadataArgs) -> Result<()> {
+    let changes = collect_changes(&args)?;
+
+    let payer = config.fee_payer()?;
+    let signer = config.signer_or_default(args.update_authority.as_ref(), "update authority")?;
+    let signer_pubkey = signer.pubkey();
+    status!("🔑 Signer: {}", signer_pubkey);
+
+    let (current, mut new_metadata) = fetch_existing_metadata(config, &args.mint)?;
+    check_update_authority(&new_metadata, &signer_pubkey)?;
+
+    // Застосовуємо зміни локально: так видно найбільший проміжний розмір для ренти
+    let mut instructions = vec![];
+    let mut max_size = new_metadata.tlv_size_of()?;
+    for change in changes {
+        match change {
+            MetadataChange::Set(field, value) => {
+                if field_value(&new_metadata, &field) == Some(value.as_str()) {
+                    continue;
+                }
+                new_metadata.update(field.clone(), value.clone());
+                instructions.push(update_field(
+                    &spl_token_2022::id(),
+                    &args.mint,
+                    &signer_pubkey,
+                    field,
+                    value,
+                ));
+            }
+            MetadataChange::Remove(key) => {
+                if !new_metadata.remove_key(&key) {
+                    status!("⚠️  Field '{}' does not exist, skipping", key);
+                    continue;
+                }
+                instructions.push(remove_key(
+                    &spl_token_2022::id(),
+                    &args.mint,
+                    &signer_pubkey,
+                    key,
+                    false,
+                ));
+            }
+        }
+        max_size = max_size.max(new_metadata.tlv_size_of()?);
+    }
+
+    if instructions.is_empty() {
+        status!("✅ Metadata is already up to date");
+        print_metadata(&new_metadata);
+        return Ok(());
+    }
+
+    if let Some(top_up) = rent_top_up(config, &payer.pubkey(), &args.mint, &current, max_size)? {
+        instructions.insert(0, top_up);
+    }
+
+    let batches = pack_instructions(config, &payer.pubkey(), instructions)?;
+    let total = batches.len();
+    // Кожна партія перезаписала б попередню в виводі --sign-only
+    if config.transaction.sign_only && total > 1 {
+        bail!(
+            "The update needs {} transactions, --sign-only signs a single one: apply fewer changes at a time",
+            total
+        );
+    }
+    if !send_batches(config, &payer, &[&signer], &batches)? {
+        return Ok(());
+    }
+    status!("✅ Metadata updated!");
+
+    let stored = fetch_existing_metadata(config, &args.mint)?.1;
+    if stored != new_metadata {
+        bail!("Stored metadata does not match the requested changes");
+    }
+    print_metadata(&stored);
+
+    Ok(())
+}
+
+fn authority(config: &Config, args: MetadataAuthorityArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    let signer = config.signer_or_default(args.update_authority.as_ref(), "update authority")?;
+    let signer_pubkey = signer.pubkey();
+    status!("🔑 Signer: {}", signer_pubkey);
+
+    let (_, metadata) = fetch_existing_metadata(config, &args.mint)?;
+    check_update_authority(&metadata, &signer_pubkey)?;
+
+    let update_authority_ix = update_authority(
+        &spl_token_2022::id(),
+        &args.mint,
+        &signer_pubkey,
+        args.new_authority.try_into()?,
+    );
+
+    if !send(config, &payer, &[&signer], &[update_authority_ix])? {
+        return Ok(());
+    }
+    match args.new_authority {
+        Some(new_authority) => status!("✅ Update authority changed to {}", new_authority),
+        None => status!("✅ Update authority revoked, metadata is now immutable"),
+    }
+
+    Ok(())
+}
+
+fn show(config: &Config, args: ShowMetadataArgs) -> Result<()> {
+    match fetch_mint_metadata(config, &args.mint)?.metadata {
+        Some(metadata) => print_metadata(&metadata),
+        None => {
+            output::set("metadata", Value::Null);
+            status!("📭 Mint {} has no metadata yet", args.mint)
+        }
+    }
+    status!("🔗 {}", config.cluster.explorer_address_url(args.mint));
+
+    Ok(())
+}
+
+fn init_metaplex(config: &Config, args: InitMetadataArgs) -> Result<()> {
+    if !args.fields.is_empty() {
+        bail!("Metaplex metadata has no additional fields, --field needs a Token-2022 mint");
+    }
+    let data = metaplex::token_data(args.name, args.symbol, args.uri);
+    metaplex::validate(&data)?;
+    metaplex::ensure_program_deployed(config)?;
+
+    let payer = config.fee_payer()?;
+    let signer = config.signer_or_default(args.mint_authority.as_ref(), "mint authority")?;
+    let signer_pubkey = signer.pubkey();
+    status!("🔑 Signer: {}", signer_pubkey);
+
+    if metaplex::fetch(config, &args.mint)?.is_some() {
+        bail!(
+            "Metaplex metadata of {} already exists, use `metadata update`",
+            args.mint
+        );
+    }
+
+    let create_ix = metaplex::create_instruction(
+        &args.mint,
+        &signer_pubkey,
+        &payer.pubkey(),
+        &signer_pubkey,
+        data,
+    );
+
+    if !send(config, &payer, &[&signer], &[create_ix])? {
+        return Ok(());
+    }
+    status!("✅ Metaplex metadata created!");
+
+    metaplex::print(&metaplex::fetch_existing(config, &args.mint)?);
+
+    Ok(())
+}
+
+fn update_metaplex(config: &Config, args: UpdateMetadataArgs) -> Result<()> {
+    let changes = collect_changes(&args)?;
+
+    let payer = config.fee_payer()?;
+    let signer = config.signer_or_default(args.update_authority.as_ref(), "update authority")?;
+    let signer_pubkey = signer.pubkey();
+    status!("🔑 Signer: {}", signer_pubkey);
+
+    let metadata = metaplex::fetch_existing(config, &args.mint)?;
+    metaplex::check_update_authority(&metadata, &signer_pubkey)?;
+
+    let current = metaplex::data_of(&metadata);
+    let mut data = current.clone();
+    for change in changes {
+        match change {
+            MetadataChange::Set(Field::Name, value) => data.name = value,
+            MetadataChange::Set(Field::Symbol, value) => data.symbol = value,
+            MetadataChange::Set(Field::Uri, value) => data.uri = value,
+            MetadataChange::Set(Field::Key(key), _) | MetadataChange::Remove(key) => bail!(
+                "Metaplex metadata has no additional fields, cannot change '{}'",
+                key
+            ),
+        }
+    }
+
+    if data == current {
+        status!("✅ Metadata is already up to date");
+        metaplex::print(&metadata);
+        return Ok(());
+    }
+    metaplex::validate(&data)?;
+
+    let update_ix =
+        metaplex::update_instruction(&args.mint, &signer_pubkey, Some(data), None, None);
+
+    if !send(config, &payer, &[&signer], &[update_ix])? {
+        return Ok(());
+    }
+    status!("✅ Metadata updated!");
+
+    metaplex::print(&metaplex::fetch_existing(config, &args.mint)?);
+
+    Ok(())
+}
+
+fn authority_metaplex(config: &Config, args: MetadataAuthorityArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    let signer = config.signer_or_default(args.update_authority.as_ref(), "update authority")?;
+    let signer_pubkey = signer.pubkey();
+    status!("🔑 Signer: {}", signer_pubkey);
+
+    let metadata = metaplex::fetch_existing(config, &args.mint)?;
+    metaplex::check_update_authority(&metadata, &signer_pubkey)?;
+
+    // Metaplex не дозволяє прибрати update authority, замість цього метадані стають незмінними
+    let update_ix = match args.new_authority {
+        Some(new_authority) => metaplex::update_instruction(
+            &args.mint,
+            &signer_pubkey,
+            None,
+            Some(new_authority),
+            None,
+        ),
+        None => metaplex::update_instruction(&args.mint, &signer_pubkey, None, None, Some(false)),
+    };
+
+    if !send(config, &payer, &[&signer], &[update_ix])? {
+        return Ok(());
+    }
+    match args.new_authority {
+        Some(new_authority) => status!("✅ Update authority changed to {}", new_authority),
+        None => status!("✅ Metadata is now immutable"),
+    }
+
+    Ok(())
+}
+
+fn show_metaplex(config: &Config, args: ShowMetadataArgs) -> Result<()> {
+    match metaplex::fetch(config, &args.mint)? {
+        Some(metadata) => metaplex::print(&metadata),
+        None => {
+            output::set("metadata", Value::Null);
+            status!("📭 Mint {} has no Metaplex metadata yet", args.mint)
+        }
+    }
+    status!("🔗 {}", config.cluster.explorer_address_url(args.mint));
+
+    Ok(())
+}
+
+/// Splits instructions into as few transactions as fit the packet size limit, keeping their order.
+fn pack_instructions(
+    config: &Config,
+    payer: &Pubkey,
+    instructions: Vec<Instruction>,
+) -> Result<Vec<Vec<Instruction>>> {
+    let tables = transaction::fetch_lookup_tables(config)?;
+    let mut batches: Vec<Vec<Instruction>> = vec![];
+    let mut batch = vec![];
+    for instruction in instructions {
+        batch.push(instruction);
+        if batch.len() > 1 && !transaction::fits(config, payer, &batch, &tables)? {
+            let instruction = batch.pop().expect("batch is not empty");
+            batches.push(std::mem::replace(&mut batch, vec![instruction]));
+        }
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    Ok(batches)
+}
+
+/// Sends the batches in order; false when they were only signed or simulated.
+/// `--dry-run` simulates just the first batch: the later ones build on its changes to the mint.
+fn send_batches(
+    config: &Config,
+    payer: &dyn Signer,
+    signers: &[&dyn Signer],
+    batches: &[Vec<Instruction>],
+) -> Result<bool> {
+    if config.transaction.dry_run && batches.len() > 1 {
+        status!(
+            "ℹ️  Only transaction 1/{} is simulated, the others depend on the changes it makes",
+            batches.len()
+        );
+        return send(config, payer, signers, &batches[0]);
+    }
+
+    let mut sent = true;
+    for (index, batch) in batches.iter().enumerate() {
+        if batches.len() > 1 {
+            status!("📦 Transaction {}/{}", index + 1, batches.len());
+        }
+        sent = send(config, payer, signers, batch)?;
+    }
+    Ok(sent)
+}
+
+/// Sends one transaction; false when it was only signed (--sign-only).
+fn send(
+    config: &Config,
+    payer: &dyn Signer,
+    signers: &[&dyn Signer],
+    instructions: &[Instruction],
+) -> Result<bool> {
+    let Some(sig) = transaction::process(config, payer, signers, instructions)? else {
+        return Ok(false);
+    };
+    status!("🔗 {}", config.cluster.explorer_tx_url(sig));
+    output::push("signatures", sig.to_string());
+
+    Ok(true)
+}
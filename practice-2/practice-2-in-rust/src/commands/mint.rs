@@ -0,0 +1,278 @@
+use anyhow::{bail, Result};
+use clap::{Args, Subcommand};
+use solana_sdk::{
+    instruction::Instruction, native_token::Sol, pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+use solana_system_interface::instruction as system_instruction;
+use spl_token_2022::{
+    extension::{
+        default_account_state, interest_bearing_mint, metadata_pointer, transfer_fee,
+        transfer_fee::MAX_FEE_BASIS_POINTS, transfer_hook, ExtensionType,
+    },
+    id as token_2022_program_id,
+    instruction::{
+        initialize_mint2, initialize_mint_close_authority, initialize_non_transferable_mint,
+        initialize_permanent_delegate,
+    },
+    state::{AccountState, Mint},
+};
+
+use crate::{config::Config, output, signer::SignerSource, transaction};
+
+#[derive(Subcommand)]
+pub enum MintCommand {
+    /// Create a new Token-2022 mint with the selected extensions
+    Create(CreateMintArgs),
+}
+
+#[derive(Args)]
+pub struct CreateMintArgs {
+    /// Number of decimal places of the token
+    #[arg(long, default_value_t = 2)]
+    pub decimals: u8,
+
+    /// Mint authority [default: --keypair]
+    #[arg(long)]
+    pub mint_authority: Option<Pubkey>,
+
+    /// Freeze authority, none if omitted
+    #[arg(long)]
+    pub freeze_authority: Option<Pubkey>,
+
+    /// Pre-generated mint keypair, e.g. from `spl grind` [default: random]
+    #[arg(long)]
+    pub mint_keypair: Option<SignerSource>,
+
+    #[command(flatten)]
+    pub extensions: MintExtensionArgs,
+}
+
+/// Token-2022 extensions enabled on the new mint. Extension authorities default to the mint authority.
+#[derive(Args)]
+pub struct MintExtensionArgs {
+    /// Skip the MetadataPointer extension (enabled by default, needed for embedded metadata)
+    #[arg(long)]
+    pub no_metadata_pointer: bool,
+
+    /// Transfer fee in basis points, requires --max-transfer-fee
+    #[arg(long, requires = "max_transfer_fee")]
+    pub transfer_fee_bps: Option<u16>,
+
+    /// Maximum transfer fee in base units, requires --transfer-fee-bps
+    #[arg(long, requires = "transfer_fee_bps")]
+    pub max_transfer_fee: Option<u64>,
+
+    /// Interest rate in basis points per year (may be negative)
+    #[arg(long, allow_negative_numbers = true)]
+    pub interest_rate: Option<i16>,
+
+    /// Make tokens non-transferable (soulbound)
+    #[arg(long, conflicts_with_all = ["transfer_fee_bps", "transfer_hook"])]
+    pub non_transferable: bool,
+
+    /// Permanent delegate that can transfer or burn from any account
+    #[arg(long)]
+    pub permanent_delegate: Option<Pubkey>,
+
+    /// New token accounts start frozen, requires --freeze-authority
+    #[arg(long, requires = "freeze_authority")]
+    pub default_account_state_frozen: bool,
+
+    /// Authority allowed to close the mint once supply is zero
+    #[arg(long)]
+    pub close_authority: Option<Pubkey>,
+
+    /// Transfer hook program invoked on every transfer
+    #[arg(long)]
+    pub transfer_hook: Option<Pubkey>,
+}
+
+impl MintExtensionArgs {
+    pub fn extension_types(&self) -> Vec<ExtensionType> {
+        let mut extensions = vec![];
+        if !self.no_metadata_pointer {
+            extensions.push(ExtensionType::MetadataPointer);
+        }
+        if self.transfer_fee_bps.is_some() {
+            extensions.push(ExtensionType::TransferFeeConfig);
+        }
+        if self.interest_rate.is_some() {
+            extensions.push(ExtensionType::InterestBearingConfig);
+        }
+        if self.non_transferable {
+            extensions.push(ExtensionType::NonTransferable);
+        }
+        if self.permanent_delegate.is_some() {
+            extensions.push(ExtensionType::PermanentDelegate);
+        }
+        if self.default_account_state_frozen {
+            extensions.push(ExtensionType::DefaultAccountState);
+        }
+        if self.close_authority.is_some() {
+            extensions.push(ExtensionType::MintCloseAuthority);
+        }
+        if self.transfer_hook.is_some() {
+            extensions.push(ExtensionType::TransferHook);
+        }
+        extensions
+    }
+
+    /// Extension initialize instructions; they must run after create_account and before initialize_mint.
+    pub fn instructions(&self, mint: &Pubkey, authority: &Pubkey) -> Result<Vec<Instruction>> {
+        let program_id = token_2022_program_id();
+        let mut instructions = vec![];
+
+        if !self.no_metadata_pointer {
+            instructions.push(metadata_pointer::instruction::initialize(
+                &program_id,
+                mint,
+                Some(*authority),
+                Some(*mint),
+            )?);
+        }
+        if let (Some(bps), Some(max_fee)) = (self.transfer_fee_bps, self.max_transfer_fee) {
+            if bps > MAX_FEE_BASIS_POINTS {
+                bail!("Transfer fee cannot exceed {} bps", MAX_FEE_BASIS_POINTS);
+            }
+            instructions.push(transfer_fee::instruction::initialize_transfer_fee_config(
+                &program_id,
+                mint,
+                Some(authority),
+                Some(authority),
+                bps,
+                max_fee,
+            )?);
+        }
+        if let Some(rate) = self.interest_rate {
+            instructions.push(interest_bearing_mint::instruction::initialize(
+                &program_id,
+                mint,
+                Some(*authority),
+                rate,
+            )?);
+        }
+        if self.non_transferable {
+            instructions.push(initialize_non_transferable_mint(&program_id, mint)?);
+        }
+        if let Some(delegate) = &self.permanent_delegate {
+            instructions.push(initialize_permanent_delegate(&program_id, mint, delegate)?);
+        }
+        if self.default_account_state_frozen {
+            instructions.push(
+                default_account_state::instruction::initialize_default_account_state(
+                    &program_id,
+                    mint,
+                    &AccountState::Frozen,
+                )?,
+            );
+        }
+        if let Some(close_authority) = &self.close_authority {
+            instructions.push(initialize_mint_close_authority(
+                &program_id,
+                mint,
+                Some(close_authority),
+            )?);
+        }
+        if let Some(hook_program_id) = self.transfer_hook {
+            instructions.push(transfer_hook::instruction::initialize(
+                &program_id,
+                mint,
+                Some(*authority),
+                Some(hook_program_id),
+            )?);
+        }
+
+        Ok(instructions)
+    }
+}
+
+pub fn run(config: &Config, command: MintCommand) -> Result<()> {
+    match command {
+        MintCommand::Create(args) => create(config, args),
+    }
+}
+
+fn create(config: &Config, args: CreateMintArgs) -> Result<()> {
+    let sender_keypair = config.fee_payer()?;
+    let mint_authority = match args.mint_authority {
+        Some(authority) => authority,
+        None => config.default_signer()?.pubkey(),
+    };
+    let mint_account = match &args.mint_keypair {
+        Some(source) => source.load("mint")?,
+        None => Keypair::new(),
+    };
+    status!("🔑 Sender public key: {}", sender_keypair.pubkey());
+
+    let connection = &config.rpc;
+
+    // Розмір акаунта рахуємо з обраних розширень
+    let extensions = args.extensions.extension_types();
+    let mint_space = ExtensionType::try_calculate_account_len::<Mint>(&extensions)?;
+    // Без мережі (--sign-only з --blockhash) існування акаунта не перевірити
+    if !config.transaction.offline() && connection.get_balance(&mint_account.pubkey())? > 0 {
+        bail!("Mint account {} already exists", mint_account.pubkey());
+    }
+    let mint_rent = config.rent_exempt_minimum(mint_space)?;
+
+    // Усі інструкції в одній транзакції: або мінт створено повністю, або нічого
+    let mut instructions = vec![system_instruction::create_account(
+        &sender_keypair.pubkey(),
+        &mint_account.pubkey(),
+        mint_rent,
+        mint_space as u64,
+        &token_2022_program_id(),
+    )];
+    // Розширення ініціалізуються до initialize_mint
+    instructions.extend(
+        args.extensions
+            .instructions(&mint_account.pubkey(), &mint_authority)?,
+    );
+    instructions.push(initialize_mint2(
+        &token_2022_program_id(),
+        &mint_account.pubkey(),
+        &mint_authority,
+        args.freeze_authority.as_ref(),
+        args.decimals,
+    )?);
+
+    output::set("mint", mint_account.pubkey().to_string());
+    output::set("decimals", args.decimals);
+    output::set("mint_authority", mint_authority.to_string());
+    output::set(
+        "freeze_authority",
+        args.freeze_authority.map(|authority| authority.to_string()),
+    );
+    output::set(
+        "extensions",
+        extensions
+            .iter()
+            .map(|extension| format!("{:?}", extension))
+            .collect::<Vec<_>>(),
+    );
+    output::set("space", mint_space);
+    output::set("rent", mint_rent);
+
+    let Some(sig) = transaction::process(config, &sender_keypair, &[&mint_account], &instructions)?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Mint created: {}", mint_account.pubkey());
+    status!("   decimals: {}", args.decimals);
+    status!("   space: {} bytes, rent: {}", mint_space, Sol(mint_rent));
+    status!("   extensions: {:?}", extensions);
+    status!("   mint authority: {}", mint_authority);
+    match args.freeze_authority {
+        Some(freeze_authority) => status!("   freeze authority: {}", freeze_authority),
+        None => status!("   freeze authority: none"),
+    }
+    status!("Transaction Signature: {}", sig);
+    status!("🔗 {}", config.cluster.explorer_tx_url(sig));
+    status!(
+        "Mint: {}",
+        config.cluster.explorer_address_url(mint_account.pubkey())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use anyhow::{bail, Result};
+use clap::{ArgGroup, Args};
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token_2022::instruction::mint_to_checked;
+
+use crate::{
+    authority::MultisigArgs,
+    config::Config,
+    output,
+    signer::SignerSource,
+    token::{format_amount, parse_amount, OfflineMintArgs},
+    transaction,
+};
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("recipient").required(true).args(["owner", "account"])))]
+pub struct MintToArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Wallet that receives the tokens, its associated token account is created if missing
+    #[arg(long)]
+    pub owner: Option<Pubkey>,
+
+    /// Existing token account that receives the tokens
+    #[arg(long)]
+    pub account: Option<Pubkey>,
+
+    /// Amount in tokens, e.g. 12.5 (converted with the mint's decimals)
+    #[arg(long)]
+    pub amount: String,
+
+    /// Mint authority signer [default: --keypair]
+    #[arg(long, conflicts_with = "multisig")]
+    pub mint_authority: Option<SignerSource>,
+
+    #[command(flatten)]
+    pub multisig: MultisigArgs,
+
+    #[command(flatten)]
+    pub offline: OfflineMintArgs,
+}
+
+pub fn run(config: &Config, args: MintToArgs) -> Result<()> {
+    let sender_keypair = config.fee_payer()?;
+    status!("🔑 Sender public key: {}", sender_keypair.pubkey());
+    // Перевірка мінт акаунта
+    let mint = args.offline.mint(config, &args.mint)?;
+    let mint_authority = args.multisig.authority(
+        config,
+        &mint.program_id,
+        args.mint_authority.as_ref(),
+        "mint authority",
+    )?;
+
+    match mint.mint_authority {
+        // Офлайн мінт не завантажено, авторитет перевірить програма
+        _ if config.transaction.offline() => {}
+        Some(authority) if authority == mint_authority.address => {}
+        Some(authority) => bail!(
+            "{} is not the mint authority of {}, the authority is {}",
+            mint_authority.address,
+            args.mint,
+            authority
+        ),
+        None => bail!("Mint {} has a fixed supply, no mint authority", args.mint),
+    }
+    if !config.transaction.offline() {
+        status!("✅ Mint account found.");
+    }
+
+    let amount = parse_amount(&args.amount, mint.decimals)?;
+
+    let mut instructions = vec![];
+    let recipient = match (args.owner, args.account) {
+        (_, Some(account)) => account,
+        (Some(owner), None) => {
+            instructions.push(create_associated_token_account_idempotent(
+                &sender_keypair.pubkey(),
+                &owner,
+                &args.mint,
+                &mint.program_id,
+            ));
+            get_associated_token_address_with_program_id(&owner, &args.mint, &mint.program_id)
+        }
+        (None, None) => unreachable!("clap requires --owner or --account"),
+    };
+    status!("Recipient token account: {}", recipient);
+
+    instructions.push(mint_to_checked(
+        &mint.program_id,
+        &args.mint,
+        &recipient,
+        &mint_authority.address,
+        &mint_authority.multisig_signers(),
+        amount,
+        mint.decimals,
+    )?);
+
+    output::set("mint", args.mint.to_string());
+    output::set("account", recipient.to_string());
+    output::set("amount", amount.to_string());
+    output::set("ui_amount", format_amount(amount, mint.decimals));
+    output::set("decimals", mint.decimals);
+
+    let Some(signature) = transaction::process(
+        config,
+        &sender_keypair,
+        &mint_authority.signers(),
+        &instructions,
+    )?
+    else {
+        return Ok(());
+    };
+
+    status!(
+        "✅ Success. {} tokens minted.",
+        format_amount(amount, mint.decimals)
+    );
+    status!(
+        "Mint Transaction: {}",
+        config.cluster.explorer_tx_url(signature)
+    );
+
+    Ok(())
+}
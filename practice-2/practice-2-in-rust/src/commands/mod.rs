@@ -0,0 +1,23 @@
+pub mod account;
+pub mod approve;
+pub mod balances;
+pub mod broadcast;
+pub mod burn;
+pub mod close_account;
+pub mod freeze;
+pub mod grind;
+pub mod inspect;
+pub mod keys;
+pub mod lookup_table;
+pub mod metadata;
+pub mod mint;
+pub mod mint_to;
+pub mod multisig;
+pub mod nonce;
+pub mod payout;
+pub mod revoke;
+pub mod set_authority;
+pub mod sign;
+pub mod transfer;
+pub mod transfer_token;
+pub mod wallet;
@@ -0,0 +1,161 @@
+use anyhow::{bail, Result};
+use clap::{Args, Subcommand};
+use solana_program::program_pack::Pack;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::instruction as system_instruction;
+use spl_token_2022::{instruction::initialize_multisig2, state::Multisig};
+
+use crate::{
+    authority::fetch_multisig, config::Config, output, signer::SignerSource, token::TokenProgram,
+    transaction,
+};
+
+/// Token programs accept at most 11 multisig signers.
+const MAX_SIGNERS: usize = spl_token_2022::instruction::MAX_SIGNERS;
+
+#[derive(Subcommand)]
+pub enum MultisigCommand {
+    /// Create an M-of-N multisig usable as mint, freeze or account owner authority
+    Create(CreateMultisigArgs),
+
+    /// Print the threshold and signers of a multisig
+    Show(ShowMultisigArgs),
+}
+
+#[derive(Args)]
+pub struct CreateMultisigArgs {
+    /// Public key of a multisig member (repeat 1 to 11 times)
+    #[arg(long = "signer", required = true)]
+    pub signers: Vec<Pubkey>,
+
+    /// Number of signatures required (M)
+    #[arg(long)]
+    pub threshold: u8,
+
+    /// Token program that will own the multisig, it must match the mints it controls
+    #[arg(long, value_enum, default_value_t = TokenProgram::Token2022)]
+    pub program: TokenProgram,
+
+    /// Keypair for the multisig account address [default: random]
+    #[arg(long)]
+    pub multisig_keypair: Option<SignerSource>,
+}
+
+#[derive(Args)]
+pub struct ShowMultisigArgs {
+    /// Multisig account address
+    pub address: Pubkey,
+}
+
+pub fn run(config: &Config, command: MultisigCommand) -> Result<()> {
+    match command {
+        MultisigCommand::Create(args) => create(config, args),
+        MultisigCommand::Show(args) => show(config, args),
+    }
+}
+
+fn create(config: &Config, args: CreateMultisigArgs) -> Result<()> {
+    if args.signers.len() > MAX_SIGNERS {
+        bail!("A multisig has at most {} signers", MAX_SIGNERS);
+    }
+    if args.threshold == 0 || args.threshold as usize > args.signers.len() {
+        bail!(
+            "--threshold must be between 1 and the number of signers ({})",
+            args.signers.len()
+        );
+    }
+    for (index, signer) in args.signers.iter().enumerate() {
+        if args.signers[..index].contains(signer) {
+            bail!("Signer {} is listed more than once", signer);
+        }
+    }
+
+    let payer = config.fee_payer()?;
+    status!("🔑 Payer: {}", payer.pubkey());
+
+    let multisig_keypair = match &args.multisig_keypair {
+        Some(source) => source.load("multisig")?,
+        None => Keypair::new(),
+    };
+    let multisig = multisig_keypair.pubkey();
+    let program_id = args.program.id();
+
+    let lamports = config.rent_exempt_minimum(Multisig::LEN)?;
+    let signer_refs: Vec<&Pubkey> = args.signers.iter().collect();
+
+    let instructions = [
+        system_instruction::create_account(
+            &payer.pubkey(),
+            &multisig,
+            lamports,
+            Multisig::LEN as u64,
+            &program_id,
+        ),
+        initialize_multisig2(&program_id, &multisig, &signer_refs, args.threshold)?,
+    ];
+
+    output::set("multisig", multisig.to_string());
+    output::set("threshold", args.threshold);
+    output::set(
+        "signers",
+        args.signers
+            .iter()
+            .map(|signer| signer.to_string())
+            .collect::<Vec<_>>(),
+    );
+    output::set("program_id", program_id.to_string());
+
+    let Some(signature) =
+        transaction::process(config, &payer, &[&multisig_keypair], &instructions)?
+    else {
+        return Ok(());
+    };
+
+    status!(
+        "✅ Multisig {} of {} created: {}",
+        args.threshold,
+        args.signers.len(),
+        multisig
+    );
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+    status!("Use it as --mint-authority, --freeze-authority or account --owner,");
+    status!(
+        "then sign with --multisig {} --multisig-signer ...",
+        multisig
+    );
+
+    Ok(())
+}
+
+pub fn show(config: &Config, args: ShowMultisigArgs) -> Result<()> {
+    let (program_id, multisig) = fetch_multisig(config, &args.address)?;
+    let program = if program_id == spl_token::id() {
+        "SPL Token"
+    } else {
+        "Token-2022"
+    };
+
+    let signers = &multisig.signers[..multisig.n as usize];
+    output::set("multisig", args.address.to_string());
+    output::set("program_id", program_id.to_string());
+    output::set("threshold", multisig.m);
+    output::set(
+        "signers",
+        signers
+            .iter()
+            .map(|signer| signer.to_string())
+            .collect::<Vec<_>>(),
+    );
+
+    status!("🔐 Multisig {}", args.address);
+    status!("   program: {}", program);
+    status!("   threshold: {} of {}", multisig.m, multisig.n);
+    for signer in signers {
+        status!("   signer: {}", signer);
+    }
+
+    Ok(())
+}
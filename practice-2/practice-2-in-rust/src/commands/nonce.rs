@@ -0,0 +1,226 @@
+use anyhow::{bail, Context, Result};
+use clap::{Args, Subcommand};
+use solana_nonce::state::State;
+use solana_sdk::{
+    native_token::Sol,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::instruction as system_instruction;
+
+use super::transfer::parse_sol;
+use crate::{config::Config, output, signer::SignerSource, transaction};
+
+#[derive(Subcommand)]
+pub enum NonceCommand {
+    /// Create a durable nonce account for transactions that are signed over a long time
+    Create(CreateNonceArgs),
+
+    /// Print the authority and current nonce of a nonce account
+    Show(ShowNonceArgs),
+
+    /// Replace the stored nonce, invalidating transactions signed with the old one
+    Advance(AdvanceNonceArgs),
+
+    /// Withdraw SOL from a nonce account (withdrawing everything closes it)
+    Withdraw(WithdrawNonceArgs),
+}
+
+#[derive(Args)]
+pub struct CreateNonceArgs {
+    /// Keypair for the nonce account address [default: random]
+    #[arg(long)]
+    pub nonce_keypair: Option<SignerSource>,
+
+    /// Nonce authority, e.g. a cold wallet or one of the co-signers [default: fee payer]
+    #[arg(long)]
+    pub authority: Option<Pubkey>,
+
+    /// SOL to deposit [default: rent-exempt minimum]
+    #[arg(long, value_parser = parse_sol)]
+    pub amount: Option<u64>,
+}
+
+#[derive(Args)]
+pub struct ShowNonceArgs {
+    /// Nonce account address
+    pub address: Pubkey,
+}
+
+#[derive(Args)]
+pub struct AdvanceNonceArgs {
+    /// Nonce account address
+    pub address: Pubkey,
+
+    /// Nonce authority signer [default: --keypair]
+    #[arg(long)]
+    pub authority: Option<SignerSource>,
+}
+
+#[derive(Args)]
+pub struct WithdrawNonceArgs {
+    /// Nonce account address
+    pub address: Pubkey,
+
+    /// Recipient wallet address
+    #[arg(long)]
+    pub to: Pubkey,
+
+    /// Amount of SOL to withdraw, e.g. 0.5
+    #[arg(long, value_parser = parse_sol)]
+    pub amount: u64,
+
+    /// Nonce authority signer [default: --keypair]
+    #[arg(long)]
+    pub authority: Option<SignerSource>,
+}
+
+pub fn run(config: &Config, command: NonceCommand) -> Result<()> {
+    // Операції з самим nonce-акаунтом не можуть використовувати його ж як blockhash
+    if config.transaction.nonce.is_some() {
+        bail!("--nonce cannot be used with nonce account commands");
+    }
+
+    match command {
+        NonceCommand::Create(args) => create(config, args),
+        NonceCommand::Show(args) => show(config, args),
+        NonceCommand::Advance(args) => advance(config, args),
+        NonceCommand::Withdraw(args) => withdraw(config, args),
+    }
+}
+
+fn create(config: &Config, args: CreateNonceArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    status!("🔑 Payer: {}", payer.pubkey());
+
+    let nonce_keypair = match &args.nonce_keypair {
+        Some(source) => source.load("nonce account")?,
+        None => Keypair::new(),
+    };
+    let nonce = nonce_keypair.pubkey();
+    let authority = args.authority.unwrap_or_else(|| payer.pubkey());
+
+    let minimum = config.rent_exempt_minimum(State::size())?;
+    let lamports = args.amount.unwrap_or(minimum);
+    if lamports < minimum {
+        bail!(
+            "--amount must be at least {} to keep the nonce account rent-exempt",
+            Sol(minimum)
+        );
+    }
+
+    let instructions =
+        system_instruction::create_nonce_account(&payer.pubkey(), &nonce, &authority, lamports);
+
+    output::set("nonce", nonce.to_string());
+    output::set("authority", authority.to_string());
+    output::set("lamports", lamports);
+
+    let Some(signature) = transaction::process(config, &payer, &[&nonce_keypair], &instructions)?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Nonce account created: {}", nonce);
+    status!("   authority: {}", authority);
+    status!("   balance: {}", Sol(lamports));
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+    status!(
+        "Sign transactions with --nonce {} --nonce-authority ...",
+        nonce
+    );
+
+    Ok(())
+}
+
+pub fn show(config: &Config, args: ShowNonceArgs) -> Result<()> {
+    let data = transaction::fetch_nonce(config, &args.address)?;
+    let balance = config
+        .rpc
+        .get_balance(&args.address)
+        .context("Failed to fetch balance")?;
+
+    output::set("nonce", args.address.to_string());
+    output::set("authority", data.authority.to_string());
+    output::set("blockhash", data.blockhash().to_string());
+    output::set("lamports_per_signature", data.get_lamports_per_signature());
+    output::set("lamports", balance);
+
+    status!("🔢 Nonce account {}", args.address);
+    status!("   authority: {}", data.authority);
+    status!("   nonce: {}", data.blockhash());
+    status!(
+        "   fee: {} lamports per signature",
+        data.get_lamports_per_signature()
+    );
+    status!("   balance: {}", Sol(balance));
+
+    Ok(())
+}
+
+fn advance(config: &Config, args: AdvanceNonceArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    let authority = config.signer_or_default(args.authority.as_ref(), "nonce authority")?;
+    check_authority(config, &args.address, &authority.pubkey())?;
+
+    let instruction = system_instruction::advance_nonce_account(&args.address, &authority.pubkey());
+    output::set("nonce", args.address.to_string());
+
+    let Some(signature) = transaction::process(config, &payer, &[&authority], &[instruction])?
+    else {
+        return Ok(());
+    };
+
+    let data = transaction::fetch_nonce(config, &args.address)?;
+    status!("✅ Nonce advanced, new nonce: {}", data.blockhash());
+    output::set("blockhash", data.blockhash().to_string());
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
+
+fn withdraw(config: &Config, args: WithdrawNonceArgs) -> Result<()> {
+    let payer = config.fee_payer()?;
+    let authority = config.signer_or_default(args.authority.as_ref(), "nonce authority")?;
+    check_authority(config, &args.address, &authority.pubkey())?;
+
+    status!("💸 Withdrawing {} to {}", Sol(args.amount), args.to);
+    output::set("nonce", args.address.to_string());
+    output::set("to", args.to.to_string());
+    output::set("lamports", args.amount);
+
+    let instruction = system_instruction::withdraw_nonce_account(
+        &args.address,
+        &authority.pubkey(),
+        &args.to,
+        args.amount,
+    );
+
+    let Some(signature) = transaction::process(config, &payer, &[&authority], &[instruction])?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Withdrawn, signature: {}", signature);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
+
+fn check_authority(config: &Config, address: &Pubkey, authority: &Pubkey) -> Result<()> {
+    // Без мережі (--sign-only з --blockhash) перевірку пропускаємо
+    if config.transaction.offline() {
+        return Ok(());
+    }
+
+    let data = transaction::fetch_nonce(config, address)?;
+    if data.authority != *authority {
+        bail!(
+            "Nonce authority of {} is {}, not {}",
+            address,
+            data.authority,
+            authority
+        );
+    }
+    Ok(())
+}
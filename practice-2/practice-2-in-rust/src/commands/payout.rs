@@ -0,0 +1,768 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+};
+
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    native_token::Sol,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+};
+use solana_system_interface::instruction as system_instruction;
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_memo::build_memo;
+use spl_token_2022::instruction::transfer_checked;
+
+use super::transfer::parse_sol;
+use crate::{
+    config::Config,
+    output,
+    signer::SignerSource,
+    token::{fetch_mint, format_amount, parse_amount, MintInfo},
+    transaction,
+};
+
+/// `getSignatureStatuses` and `getMultipleAccounts` limits.
+const MAX_RPC_BATCH: usize = 100;
+
+#[derive(Args)]
+pub struct PayoutArgs {
+    /// CSV file with `recipient,amount[,memo]` rows, amounts in SOL or in tokens with --mint
+    pub csv: PathBuf,
+
+    /// Token mint to pay out instead of SOL
+    #[arg(long)]
+    pub mint: Option<Pubkey>,
+
+    /// Wallet paying out [default: --keypair]
+    #[arg(long)]
+    pub from: Option<SignerSource>,
+
+    /// Journal of sent rows that lets an interrupted payout resume [default: <csv>.journal]
+    #[arg(long)]
+    pub journal: Option<PathBuf>,
+
+    /// Transactions in flight at the same time
+    #[arg(long, default_value_t = 4)]
+    pub concurrency: usize,
+
+    /// Most transfers per transaction [default: as many as fit]
+    #[arg(long)]
+    pub batch_size: Option<usize>,
+}
+
+struct Row {
+    line: usize,
+    recipient: Pubkey,
+    amount: u64,
+    memo: Option<String>,
+}
+
+/// What is paid out: SOL, or tokens from the sender's associated token account.
+enum Asset {
+    Sol,
+    Token {
+        mint: Pubkey,
+        info: Box<MintInfo>,
+        source: Pubkey,
+    },
+}
+
+impl Asset {
+    fn mint(&self) -> Option<Pubkey> {
+        match self {
+            Asset::Sol => None,
+            Asset::Token { mint, .. } => Some(*mint),
+        }
+    }
+
+    fn format(&self, amount: u64) -> String {
+        match self {
+            Asset::Sol => Sol(amount).to_string(),
+            Asset::Token { info, .. } => format!("{} tokens", format_amount(amount, info.decimals)),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Batch<'a> {
+    rows: Vec<&'a Row>,
+    instructions: Vec<Instruction>,
+}
+
+pub fn run(config: &Config, args: PayoutArgs) -> Result<()> {
+    if config.transaction.sign_only {
+        bail!("payout sends many transactions and cannot be used with --sign-only");
+    }
+    // Один nonce не можна використати паралельно в кількох транзакціях
+    if config.transaction.nonce.is_some() {
+        bail!("payout cannot be used with --nonce");
+    }
+    if args.concurrency == 0 {
+        bail!("--concurrency must be at least 1");
+    }
+
+    let payer = config.fee_payer_keypair()?;
+    let sender = config.keypair_or_default(args.from.as_ref(), "sender")?;
+    status!("🔑 Sender: {}", sender.pubkey());
+
+    let asset = match args.mint {
+        None => Asset::Sol,
+        Some(mint) => {
+            let info = Box::new(fetch_mint(config, &mint)?);
+            if info.transfer_hook_program_id.is_some() {
+                bail!(
+                    "Mint {} has a transfer hook, send its tokens one by one with transfer-token",
+                    mint
+                );
+            }
+            let source = get_associated_token_address_with_program_id(
+                &sender.pubkey(),
+                &mint,
+                &info.program_id,
+            );
+            Asset::Token { mint, info, source }
+        }
+    };
+    let decimals = match &asset {
+        Asset::Sol => None,
+        Asset::Token { info, .. } => Some(info.decimals),
+    };
+    let rows = read_rows(&args.csv, decimals)?;
+
+    let journal_path = args
+        .journal
+        .clone()
+        .unwrap_or_else(|| args.csv.with_extension("journal"));
+    let (journal, mut entries) = Journal::open(&journal_path, config.transaction.dry_run)?;
+    reconcile(config, &journal, &mut entries)?;
+    output::set("journal", &journal_path);
+
+    let mint = asset.mint().map(|mint| mint.to_string());
+    let mut paid_before = HashSet::new();
+    for row in &rows {
+        let Some(entry) = entries.get(&row.line) else {
+            continue;
+        };
+        if entry.recipient != row.recipient.to_string()
+            || entry.amount != row.amount
+            || entry.mint != mint
+        {
+            bail!(
+                "Line {} of {} changed since it was journaled in {}, restore it or pass a new --journal",
+                row.line,
+                args.csv.display(),
+                journal_path.display()
+            );
+        }
+        if entry.status == Status::Confirmed {
+            paid_before.insert(row.line);
+        }
+    }
+
+    let remaining: Vec<&Row> = rows
+        .iter()
+        .filter(|row| !paid_before.contains(&row.line))
+        .collect();
+    let total = sum_amounts(&remaining)?;
+    status!(
+        "📋 {} rows, {} already paid, {} to pay: {}",
+        rows.len(),
+        paid_before.len(),
+        remaining.len(),
+        asset.format(total)
+    );
+    if remaining.is_empty() {
+        status!("✅ Every row is already paid");
+        set_payouts(&rows, &paid_before, &HashMap::new());
+        return Ok(());
+    }
+    check_balance(config, &asset, &sender.pubkey(), total)?;
+
+    let missing_accounts = missing_token_accounts(config, &asset, &remaining)?;
+    let batches = pack(
+        config,
+        &remaining,
+        &asset,
+        &payer.pubkey(),
+        &sender.pubkey(),
+        &missing_accounts,
+        args.batch_size,
+    )?;
+    status!(
+        "📦 {} transactions, {} at a time",
+        batches.len(),
+        args.concurrency.min(batches.len())
+    );
+
+    let count = batches.len();
+    let queue = Mutex::new(batches.into_iter().enumerate());
+    let results: Mutex<HashMap<usize, Result<Option<Signature>, String>>> =
+        Mutex::new(HashMap::new());
+    thread::scope(|scope| {
+        for _ in 0..args.concurrency.min(count) {
+            scope.spawn(|| loop {
+                let Some((index, batch)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                status!(
+                    "📦 Transaction {}/{}: {} transfers",
+                    index + 1,
+                    count,
+                    batch.rows.len()
+                );
+                let result = send(config, &journal, &payer, &sender, mint.as_deref(), &batch);
+                if let Err(err) = &result {
+                    status!("❌ Transaction {}/{} failed: {:#}", index + 1, count, err);
+                }
+                let result = result.map_err(|err| format!("{:#}", err));
+                let mut results = results.lock().unwrap();
+                for row in &batch.rows {
+                    results.insert(row.line, result.clone());
+                }
+            });
+        }
+    });
+
+    let results = results.into_inner().unwrap();
+    set_payouts(&rows, &paid_before, &results);
+    if config.transaction.dry_run {
+        return Ok(());
+    }
+
+    let failed: Vec<&Row> = remaining
+        .iter()
+        .copied()
+        .filter(|row| !matches!(results.get(&row.line), Some(Ok(Some(_)))))
+        .collect();
+    let paid = remaining.len() - failed.len();
+    status!(
+        "✅ Paid {} rows: {}",
+        paid,
+        asset.format(total - sum_amounts(&failed)?)
+    );
+    status!("📒 Journal: {}", journal_path.display());
+    if !failed.is_empty() {
+        bail!(
+            "{} rows were not paid, run the same command again to retry them",
+            failed.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Total of the rows, rejecting CSV files whose amounts overflow `u64`.
+fn sum_amounts(rows: &[&Row]) -> Result<u64> {
+    rows.iter()
+        .try_fold(0u64, |total, row| total.checked_add(row.amount))
+        .context("The amounts of the CSV add up to more than u64::MAX base units")
+}
+
+/// Parses `recipient,amount[,memo]` rows; an optional header line and `#` comments are skipped.
+fn read_rows(path: &Path, decimals: Option<u8>) -> Result<Vec<Row>> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let mut rows = Vec::new();
+    let mut first = true;
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.splitn(3, ',');
+        let recipient = fields.next().unwrap_or_default().trim();
+        let amount = fields.next().unwrap_or_default().trim();
+        let memo = fields
+            .next()
+            .map(|memo| memo.trim().trim_matches('"').to_string())
+            .filter(|memo| !memo.is_empty());
+
+        let amount = match decimals {
+            None => parse_sol(amount).map_err(anyhow::Error::msg),
+            Some(decimals) => parse_amount(amount, decimals),
+        };
+
+        let Ok(recipient) = recipient.parse::<Pubkey>() else {
+            // Заголовком вважаємо лише рядок без суми, інакше це помилка в адресі отримувача
+            if first && (recipient.eq_ignore_ascii_case("recipient") || amount.is_err()) {
+                first = false;
+                continue;
+            }
+            bail!(
+                "{}:{}: invalid recipient address {}",
+                path.display(),
+                line_number,
+                recipient
+            );
+        };
+        first = false;
+
+        let amount = amount
+            .with_context(|| format!("{}:{}: invalid amount", path.display(), line_number))?;
+        if amount == 0 {
+            bail!(
+                "{}:{}: amount must be positive",
+                path.display(),
+                line_number
+            );
+        }
+
+        rows.push(Row {
+            line: line_number,
+            recipient,
+            amount,
+            memo,
+        });
+    }
+
+    if rows.is_empty() {
+        bail!("No payout rows in {}", path.display());
+    }
+    Ok(rows)
+}
+
+fn check_balance(config: &Config, asset: &Asset, sender: &Pubkey, total: u64) -> Result<()> {
+    let balance = match asset {
+        Asset::Sol => config
+            .rpc
+            .get_balance(sender)
+            .context("Failed to fetch balance")?,
+        Asset::Token { source, .. } => config
+            .rpc
+            .get_token_account_balance(source)
+            .with_context(|| format!("Failed to fetch token account {}", source))?
+            .amount
+            .parse()
+            .context("Invalid token balance")?,
+    };
+    if balance < total {
+        bail!(
+            "Insufficient balance: {} to pay, {} available",
+            asset.format(total),
+            asset.format(balance)
+        );
+    }
+    Ok(())
+}
+
+/// Recipients without an associated token account for the paid mint.
+fn missing_token_accounts(
+    config: &Config,
+    asset: &Asset,
+    rows: &[&Row],
+) -> Result<HashSet<Pubkey>> {
+    let Asset::Token { mint, info, .. } = asset else {
+        return Ok(HashSet::new());
+    };
+
+    let mut recipients: Vec<Pubkey> = rows.iter().map(|row| row.recipient).collect();
+    recipients.sort();
+    recipients.dedup();
+
+    let mut missing = HashSet::new();
+    for chunk in recipients.chunks(MAX_RPC_BATCH) {
+        let addresses: Vec<Pubkey> = chunk
+            .iter()
+            .map(|recipient| {
+                get_associated_token_address_with_program_id(recipient, mint, &info.program_id)
+            })
+            .collect();
+        let accounts = config
+            .rpc
+            .get_multiple_accounts(&addresses)
+            .context("Failed to fetch recipient token accounts")?;
+        missing.extend(
+            chunk
+                .iter()
+                .zip(accounts)
+                .filter(|(_, account)| account.is_none())
+                .map(|(recipient, _)| *recipient),
+        );
+    }
+    if !missing.is_empty() {
+        status!("🆕 {} recipients get a new token account", missing.len());
+    }
+    Ok(missing)
+}
+
+/// Packs the rows into as few transactions as fit into a packet.
+fn pack<'a>(
+    config: &Config,
+    rows: &[&'a Row],
+    asset: &Asset,
+    payer: &Pubkey,
+    sender: &Pubkey,
+    missing_accounts: &HashSet<Pubkey>,
+    batch_size: Option<usize>,
+) -> Result<Vec<Batch<'a>>> {
+    let tables = transaction::fetch_lookup_tables(config)?;
+    let mut batches = Vec::new();
+    let mut batch = Batch::default();
+
+    for &row in rows {
+        let mut instructions =
+            row_instructions(row, &batch, asset, payer, sender, missing_accounts)?;
+        let full = batch_size.is_some_and(|size| batch.rows.len() >= size)
+            || !transaction::fits(
+                config,
+                payer,
+                &[&batch.instructions[..], &instructions].concat(),
+                &tables,
+            )?;
+        if full {
+            if batch.rows.is_empty() {
+                bail!(
+                    "Line {} does not fit into a transaction, shorten its memo",
+                    row.line
+                );
+            }
+            batches.push(std::mem::take(&mut batch));
+            instructions = row_instructions(row, &batch, asset, payer, sender, missing_accounts)?;
+            if !transaction::fits(config, payer, &instructions, &tables)? {
+                bail!(
+                    "Line {} does not fit into a transaction, shorten its memo",
+                    row.line
+                );
+            }
+        }
+        batch.rows.push(row);
+        batch.instructions.extend(instructions);
+    }
+    batches.push(batch);
+
+    Ok(batches)
+}
+
+fn row_instructions(
+    row: &Row,
+    batch: &Batch,
+    asset: &Asset,
+    payer: &Pubkey,
+    sender: &Pubkey,
+    missing_accounts: &HashSet<Pubkey>,
+) -> Result<Vec<Instruction>> {
+    let mut instructions = Vec::new();
+    match asset {
+        Asset::Sol => {
+            instructions.push(system_instruction::transfer(
+                sender,
+                &row.recipient,
+                row.amount,
+            ));
+        }
+        Asset::Token { mint, info, source } => {
+            // Створення ідемпотентне, тож його можна повторити в кожній транзакції з цим отримувачем
+            let created = batch
+                .rows
+                .iter()
+                .any(|other| other.recipient == row.recipient);
+            if missing_accounts.contains(&row.recipient) && !created {
+                instructions.push(create_associated_token_account_idempotent(
+                    payer,
+                    &row.recipient,
+                    mint,
+                    &info.program_id,
+                ));
+            }
+            let destination = get_associated_token_address_with_program_id(
+                &row.recipient,
+                mint,
+                &info.program_id,
+            );
+            instructions.push(transfer_checked(
+                &info.program_id,
+                source,
+                mint,
+                &destination,
+                sender,
+                &[],
+                row.amount,
+                info.decimals,
+            )?);
+        }
+    }
+    if let Some(memo) = &row.memo {
+        instructions.push(build_memo(memo.as_bytes(), &[sender]));
+    }
+    Ok(instructions)
+}
+
+/// Sends one batch, journaling its signature before every send.
+fn send(
+    config: &Config,
+    journal: &Journal,
+    payer: &Keypair,
+    sender: &Keypair,
+    mint: Option<&str>,
+    batch: &Batch,
+) -> Result<Option<Signature>> {
+    let result = transaction::process_with(config, payer, &[sender], &batch.instructions, |tx| {
+        journal.record(
+            batch,
+            mint,
+            Status::Pending,
+            Some(tx.signatures[0]),
+            Some(*tx.message.recent_blockhash()),
+        )
+    });
+
+    match &result {
+        Ok(Some(signature)) => {
+            journal.record(batch, mint, Status::Confirmed, Some(*signature), None)?;
+        }
+        Ok(None) => {}
+        Err(err) => {
+            // Інші помилки (мережа) лишають запис pending: наступний запуск перевірить підпис
+            let (code, _) = output::error_code(err);
+            if matches!(code, "transaction_failed" | "transaction_expired") {
+                journal.record(batch, mint, Status::Failed, None, None)?;
+            }
+        }
+    }
+    result
+}
+
+fn set_payouts(
+    rows: &[Row],
+    paid_before: &HashSet<usize>,
+    results: &HashMap<usize, Result<Option<Signature>, String>>,
+) {
+    let payouts: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            let (status, signature, error) = match results.get(&row.line) {
+                _ if paid_before.contains(&row.line) => ("already_paid", None, None),
+                Some(Ok(Some(signature))) => ("paid", Some(signature.to_string()), None),
+                Some(Ok(None)) => ("simulated", None, None),
+                Some(Err(err)) => ("failed", None, Some(err.clone())),
+                None => ("skipped", None, None),
+            };
+            json!({
+                "line": row.line,
+                "recipient": row.recipient.to_string(),
+                "amount": row.amount.to_string(),
+                "status": status,
+                "signature": signature,
+                "error": error,
+            })
+        })
+        .collect();
+    output::set("payouts", payouts);
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    /// Signed and about to be sent; the outcome is unknown until the signature is checked.
+    Pending,
+    Confirmed,
+    /// Failed or expired, nothing was paid.
+    Failed,
+}
+
+/// One journal line; the last line of a CSV row wins.
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    line: usize,
+    recipient: String,
+    amount: u64,
+    mint: Option<String>,
+    status: Status,
+    signature: Option<String>,
+    blockhash: Option<String>,
+}
+
+/// Append-only JSON lines file shared by the sending threads; read-only under `--dry-run`.
+struct Journal {
+    file: Option<Mutex<File>>,
+}
+
+impl Journal {
+    fn open(path: &Path, read_only: bool) -> Result<(Self, HashMap<usize, Entry>)> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to read {}", path.display()))
+            }
+        };
+
+        // Рядок без \n обірвано падінням під час запису: його транзакцію ще не відправляли
+        let complete = text.rfind('\n').map_or(0, |end| end + 1);
+        if complete < text.len() && !read_only {
+            status!("⚠️  Dropping an incomplete last line of {}", path.display());
+        }
+
+        let mut entries = HashMap::new();
+        for line in text[..complete]
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+        {
+            let entry: Entry = serde_json::from_str(line)
+                .with_context(|| format!("Corrupt journal entry in {}", path.display()))?;
+            entries.insert(entry.line, entry);
+        }
+        if !entries.is_empty() {
+            status!(
+                "📒 Resuming from {} ({} rows journaled)",
+                path.display(),
+                entries.len()
+            );
+        }
+
+        if read_only {
+            return Ok((Journal { file: None }, entries));
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        file.set_len(complete as u64)
+            .with_context(|| format!("Failed to repair {}", path.display()))?;
+
+        Ok((
+            Journal {
+                file: Some(Mutex::new(file)),
+            },
+            entries,
+        ))
+    }
+
+    /// Appends `entries` and syncs the file once for all of them.
+    fn write<'a>(&self, entries: impl IntoIterator<Item = &'a Entry>) -> Result<()> {
+        let Some(file) = &self.file else {
+            return Ok(());
+        };
+        let mut file = file.lock().unwrap();
+        for entry in entries {
+            writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        }
+        // Запис має бути на диску до відправки транзакції
+        file.sync_data().context("Failed to write the journal")
+    }
+
+    fn record(
+        &self,
+        batch: &Batch,
+        mint: Option<&str>,
+        status: Status,
+        signature: Option<Signature>,
+        blockhash: Option<Hash>,
+    ) -> Result<()> {
+        let entries: Vec<Entry> = batch
+            .rows
+            .iter()
+            .map(|row| Entry {
+                line: row.line,
+                recipient: row.recipient.to_string(),
+                amount: row.amount,
+                mint: mint.map(str::to_string),
+                status,
+                signature: signature.map(|signature| signature.to_string()),
+                blockhash: blockhash.map(|blockhash| blockhash.to_string()),
+            })
+            .collect();
+        self.write(&entries)
+    }
+}
+
+/// Resolves entries left `pending` by an interrupted run: confirmed, or failed once their
+/// blockhash expired without them landing.
+fn reconcile(
+    config: &Config,
+    journal: &Journal,
+    entries: &mut HashMap<usize, Entry>,
+) -> Result<()> {
+    let mut signatures: Vec<Signature> = entries
+        .values()
+        .filter(|entry| entry.status == Status::Pending)
+        .filter_map(|entry| entry.signature.as_ref()?.parse().ok())
+        .collect();
+    signatures.sort();
+    signatures.dedup();
+    if signatures.is_empty() {
+        return Ok(());
+    }
+    status!(
+        "🔎 Checking {} transactions of the previous run",
+        signatures.len()
+    );
+
+    let mut resolved: HashMap<String, Status> = HashMap::new();
+    for chunk in signatures.chunks(MAX_RPC_BATCH) {
+        let statuses = config
+            .rpc
+            .get_signature_statuses_with_history(chunk)
+            .context("Failed to get signature statuses")?
+            .value;
+        for (signature, status) in chunk.iter().zip(statuses) {
+            let status = match status {
+                Some(status) if status.err.is_some() => Status::Failed,
+                Some(status) if status.satisfies_commitment(config.commitment) => Status::Confirmed,
+                Some(_) => bail!(
+                    "Transaction {} of the previous run is not confirmed yet, retry in a few seconds",
+                    signature
+                ),
+                None => {
+                    let entry = entries
+                        .values()
+                        .find(|entry| entry.signature.as_deref() == Some(&signature.to_string()))
+                        .context("Journal entry disappeared")?;
+                    let blockhash: Hash = entry
+                        .blockhash
+                        .as_deref()
+                        .context("Pending journal entry without a blockhash")?
+                        .parse()
+                        .context("Invalid blockhash in the journal")?;
+                    if config
+                        .rpc
+                        .is_blockhash_valid(&blockhash, config.commitment)
+                        .context("Failed to check blockhash")?
+                    {
+                        bail!(
+                            "Transaction {} of the previous run may still land, retry in a minute",
+                            signature
+                        );
+                    }
+                    Status::Failed
+                }
+            };
+            resolved.insert(signature.to_string(), status);
+        }
+    }
+
+    let mut updated = vec![];
+    for entry in entries.values_mut() {
+        let Some(status) = entry
+            .signature
+            .as_ref()
+            .and_then(|signature| resolved.get(signature))
+        else {
+            continue;
+        };
+        if entry.status == Status::Pending {
+            entry.status = *status;
+            updated.push(&*entry);
+        }
+    }
+    journal.write(updated)
+}
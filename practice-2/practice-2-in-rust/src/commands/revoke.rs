@@ -0,0 +1,85 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::instruction::revoke;
+
+use crate::{
+    authority::MultisigArgs,
+    config::Config,
+    output,
+    signer::SignerSource,
+    token::{fetch_mint, fetch_token_account, format_amount},
+    transaction,
+};
+
+#[derive(Args)]
+pub struct RevokeArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Token account to revoke the delegate of [default: associated token account of the owner]
+    #[arg(long)]
+    pub account: Option<Pubkey>,
+
+    /// Owner of the token account [default: --keypair]
+    #[arg(long, conflicts_with = "multisig")]
+    pub owner: Option<SignerSource>,
+
+    #[command(flatten)]
+    pub multisig: MultisigArgs,
+}
+
+pub fn run(config: &Config, args: RevokeArgs) -> Result<()> {
+    let fee_payer = config.fee_payer()?;
+    let mint = fetch_mint(config, &args.mint)?;
+    let owner = args
+        .multisig
+        .authority(config, &mint.program_id, args.owner.as_ref(), "owner")?;
+
+    let account = args.account.unwrap_or_else(|| {
+        get_associated_token_address_with_program_id(&owner.address, &args.mint, &mint.program_id)
+    });
+    let token_account = fetch_token_account(config, &account, &args.mint)?;
+    if token_account.account.owner != owner.address {
+        bail!(
+            "{} is not the owner of {}, the owner is {}",
+            owner.address,
+            account,
+            token_account.account.owner
+        );
+    }
+
+    let delegate: Option<Pubkey> = token_account.account.delegate.into();
+    let Some(delegate) = delegate else {
+        bail!("Token account {} has no delegate", account);
+    };
+    status!(
+        "🚫 Revoking delegate {} ({} tokens left) of {}",
+        delegate,
+        format_amount(token_account.account.delegated_amount, mint.decimals),
+        account
+    );
+
+    let revoke_ix = revoke(
+        &mint.program_id,
+        &account,
+        &owner.address,
+        &owner.multisig_signers(),
+    )?;
+
+    output::set("mint", args.mint.to_string());
+    output::set("account", account.to_string());
+    output::set("delegate", delegate.to_string());
+
+    let Some(signature) = transaction::process(config, &fee_payer, &owner.signers(), &[revoke_ix])?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Delegate revoked, signature: {}", signature);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anyhow::{bail, Result};
+use clap::{ArgGroup, Args, ValueEnum};
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::{set_authority, AuthorityType},
+    state::{Account, Mint},
+};
+
+use crate::{
+    authority::MultisigArgs,
+    config::Config,
+    output,
+    signer::SignerSource,
+    token::{fetch_token_program_account, TokenProgram},
+    transaction,
+};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum AuthorityKind {
+    /// Mint authority of a mint
+    Mint,
+    /// Freeze authority of a mint
+    Freeze,
+    /// Owner of a token account
+    Owner,
+    /// Close authority of a token account (or of a Token-2022 mint)
+    Close,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("target").required(true).args(["new_authority", "revoke"])))]
+pub struct SetAuthorityArgs {
+    /// Mint or token account whose authority changes
+    #[arg(long)]
+    pub address: Pubkey,
+
+    /// Which authority to change
+    #[arg(long, value_enum)]
+    pub authority_type: AuthorityKind,
+
+    /// New authority, a wallet or a multisig
+    #[arg(long)]
+    pub new_authority: Option<Pubkey>,
+
+    /// Remove the authority for good (e.g. revoking the mint authority fixes the supply)
+    #[arg(long)]
+    pub revoke: bool,
+
+    /// Current authority signer [default: --keypair]
+    #[arg(long, conflicts_with = "multisig")]
+    pub authority: Option<SignerSource>,
+
+    #[command(flatten)]
+    pub multisig: MultisigArgs,
+
+    /// Token program that owns the address, required to sign offline
+    #[arg(long, value_enum)]
+    pub token_program: Option<TokenProgram>,
+}
+
+pub fn run(config: &Config, args: SetAuthorityArgs) -> Result<()> {
+    let fee_payer = config.fee_payer()?;
+    let (program_id, is_token_account) = if config.transaction.offline() {
+        offline_target(&args)?
+    } else {
+        fetch_target(config, &args.address)?
+    };
+    if let Some(token_program) = args
+        .token_program
+        .filter(|token_program| token_program.id() != program_id)
+    {
+        bail!(
+            "{} is owned by {}, not {}",
+            args.address,
+            program_id,
+            token_program.id()
+        );
+    }
+    let current = args.multisig.authority(
+        config,
+        &program_id,
+        args.authority.as_ref(),
+        "current authority",
+    )?;
+
+    let authority_type = match (args.authority_type, is_token_account) {
+        (AuthorityKind::Mint, false) => AuthorityType::MintTokens,
+        (AuthorityKind::Freeze, false) => AuthorityType::FreezeAccount,
+        (AuthorityKind::Owner, true) => AuthorityType::AccountOwner,
+        (AuthorityKind::Close, true) => AuthorityType::CloseAccount,
+        (AuthorityKind::Close, false) => AuthorityType::CloseMint,
+        (AuthorityKind::Mint | AuthorityKind::Freeze, true) => {
+            bail!("{} is a token account, not a mint", args.address)
+        }
+        (AuthorityKind::Owner, false) => bail!("{} is a mint, not a token account", args.address),
+    };
+    if args.revoke && matches!(authority_type, AuthorityType::AccountOwner) {
+        bail!("A token account always has an owner, it cannot be revoked");
+    }
+
+    output::set("address", args.address.to_string());
+    output::set("authority_type", format!("{:?}", authority_type));
+    output::set(
+        "new_authority",
+        args.new_authority.map(|authority| authority.to_string()),
+    );
+
+    let set_authority_ix = set_authority(
+        &program_id,
+        &args.address,
+        args.new_authority.as_ref(),
+        authority_type,
+        &current.address,
+        &current.multisig_signers(),
+    )?;
+
+    let Some(signature) =
+        transaction::process(config, &fee_payer, &current.signers(), &[set_authority_ix])?
+    else {
+        return Ok(());
+    };
+
+    match args.new_authority {
+        Some(new_authority) => status!("✅ Authority changed to {}", new_authority),
+        None => status!("✅ Authority revoked"),
+    }
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
+
+/// Token program of the address and whether it is a token account (otherwise a mint).
+fn fetch_target(config: &Config, address: &Pubkey) -> Result<(Pubkey, bool)> {
+    let account = fetch_token_program_account(config, address)?;
+
+    // Мультисиг теж належить токен-програмі, тому мінт розпізнаємо явно
+    if StateWithExtensions::<Account>::unpack(&account.data).is_ok() {
+        Ok((account.owner, true))
+    } else if StateWithExtensions::<Mint>::unpack(&account.data).is_ok() {
+        Ok((account.owner, false))
+    } else {
+        bail!("{} is neither a mint nor a token account", address);
+    }
+}
+
+/// Offline the address cannot be fetched: the program comes from `--token-program` and the
+/// kind of account from the authority type.
+fn offline_target(args: &SetAuthorityArgs) -> Result<(Pubkey, bool)> {
+    let Some(token_program) = args.token_program else {
+        bail!(
+            "Signing offline cannot fetch {}: pass --token-program",
+            args.address
+        );
+    };
+    let is_token_account = match args.authority_type {
+        AuthorityKind::Mint | AuthorityKind::Freeze => false,
+        AuthorityKind::Owner => true,
+        AuthorityKind::Close => bail!(
+            "Signing offline cannot tell whether {} is a mint or a token account, so --authority-type close needs the network",
+            args.address
+        ),
+    };
+    Ok((token_program.id(), is_token_account))
+}
@@ -0,0 +1,52 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use solana_sdk::signer::Signer;
+
+use crate::{config::Config, output, signer::SignerSource, transaction};
+
+#[derive(Args)]
+pub struct SignArgs {
+    /// Transaction from --sign-only: base64 string or file
+    pub transaction: String,
+
+    /// Signer to add, same formats as --keypair (repeatable) [default: --keypair]
+    #[arg(long = "signer")]
+    pub signers: Vec<SignerSource>,
+}
+
+pub fn run(config: &Config, args: SignArgs) -> Result<()> {
+    let mut tx = transaction::read(&args.transaction)?;
+
+    let signers = if args.signers.is_empty() {
+        vec![config.default_signer()?]
+    } else {
+        args.signers
+            .iter()
+            .enumerate()
+            .map(|(index, source)| -> Result<Box<dyn Signer>> {
+                Ok(Box::new(source.load(&format!("signer #{}", index + 1))?))
+            })
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let required = transaction::signer_keys(&tx);
+    for signer in &signers {
+        if !required.contains(&signer.pubkey()) {
+            bail!("{} is not a signer of this transaction", signer.pubkey());
+        }
+        status!("🔑 Signing as {}", signer.pubkey());
+    }
+
+    // Blockhash лишається тим самим, інакше попередні підписи стануть недійсними
+    let signers: Vec<&dyn Signer> = signers.iter().map(|signer| signer.as_ref()).collect();
+    transaction::partial_sign(&mut tx, &signers)?;
+
+    transaction::export(&tx, config.transaction.tx_file.as_ref())?;
+    let complete = transaction::missing_signers(&tx).is_empty();
+    output::set("complete", complete);
+    if complete {
+        status!("✅ Fully signed, send it with `spl broadcast`");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anyhow::Result;
+use clap::Args;
+use solana_sdk::{
+    native_token::{sol_str_to_lamports, Sol},
+    pubkey::Pubkey,
+    signer::Signer,
+};
+use solana_system_interface::instruction as system_instruction;
+use spl_memo::build_memo;
+
+use crate::{config::Config, output, signer::SignerSource, transaction};
+
+#[derive(Args)]
+pub struct TransferArgs {
+    /// Recipient wallet address
+    #[arg(long)]
+    pub to: Pubkey,
+
+    /// Amount of SOL to send, e.g. 0.005
+    #[arg(long, value_parser = parse_sol)]
+    pub amount: u64,
+
+    /// Optional memo attached to the transfer
+    #[arg(long)]
+    pub memo: Option<String>,
+
+    /// Wallet sending the SOL [default: --keypair]
+    #[arg(long)]
+    pub from: Option<SignerSource>,
+}
+
+pub fn parse_sol(value: &str) -> Result<u64, String> {
+    sol_str_to_lamports(value).ok_or_else(|| format!("invalid SOL amount: {}", value))
+}
+
+pub fn run(config: &Config, args: TransferArgs) -> Result<()> {
+    let sender_keypair = config.signer_or_default(args.from.as_ref(), "sender")?;
+    let fee_payer = config.fee_payer()?;
+    status!("🔑 Sender public key: {}", sender_keypair.pubkey());
+
+    status!("💸 Sending {} to {}", Sol(args.amount), args.to);
+
+    let mut instructions = vec![system_instruction::transfer(
+        &sender_keypair.pubkey(),
+        &args.to,
+        args.amount,
+    )];
+
+    if let Some(memo_text) = &args.memo {
+        instructions.push(build_memo(
+            memo_text.as_bytes(),
+            &[&sender_keypair.pubkey()],
+        ));
+    }
+
+    output::set("from", sender_keypair.pubkey().to_string());
+    output::set("to", args.to.to_string());
+    output::set("lamports", args.amount);
+    output::set("memo", &args.memo);
+
+    let Some(signature) =
+        transaction::process(config, &fee_payer, &[&sender_keypair], &instructions)?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Transaction confirmed, signature: {}", signature);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
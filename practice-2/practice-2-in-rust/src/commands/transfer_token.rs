@@ -0,0 +1,250 @@
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Args;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token_2022::{
+    extension::transfer_fee::instruction::transfer_checked_with_fee,
+    instruction::transfer_checked,
+    offchain::{add_extra_account_metas, AccountDataResult},
+};
+
+use crate::{
+    authority::MultisigArgs,
+    config::Config,
+    output,
+    signer::SignerSource,
+    token::{format_amount, parse_amount, OfflineMintArgs},
+    transaction,
+};
+
+#[derive(Args)]
+pub struct TransferTokenArgs {
+    /// Token mint address
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Recipient wallet, tokens go to its associated token account
+    #[arg(long)]
+    pub to: Pubkey,
+
+    /// Amount in tokens, e.g. 12.5 (converted with the mint's decimals)
+    #[arg(long)]
+    pub amount: String,
+
+    /// Token account to send from [default: associated token account of the owner]
+    #[arg(long)]
+    pub from_account: Option<Pubkey>,
+
+    /// Owner of the source token account [default: --keypair]
+    #[arg(long, conflicts_with = "multisig")]
+    pub from: Option<SignerSource>,
+
+    /// Create the recipient's associated token account if it does not exist (fee payer pays the rent)
+    #[arg(long)]
+    pub create_ata: bool,
+
+    #[command(flatten)]
+    pub multisig: MultisigArgs,
+
+    #[command(flatten)]
+    pub offline: OfflineMintArgs,
+}
+
+pub fn run(config: &Config, args: TransferTokenArgs) -> Result<()> {
+    let fee_payer = config.fee_payer()?;
+    let mint = args.offline.mint(config, &args.mint)?;
+    let owner = args
+        .multisig
+        .authority(config, &mint.program_id, args.from.as_ref(), "owner")?;
+
+    let connection = &config.rpc;
+    let amount = parse_amount(&args.amount, mint.decimals)?;
+
+    let source = args.from_account.unwrap_or_else(|| {
+        get_associated_token_address_with_program_id(&owner.address, &args.mint, &mint.program_id)
+    });
+    let destination =
+        get_associated_token_address_with_program_id(&args.to, &args.mint, &mint.program_id);
+
+    // Офлайн існування рахунку отримувача не перевірити, --create-ata створює його ідемпотентно
+    let mut instructions = vec![];
+    let missing = if config.transaction.offline() {
+        args.create_ata
+    } else {
+        connection
+            .get_account_with_commitment(&destination, config.commitment)?
+            .value
+            .is_none()
+    };
+    if missing {
+        if !args.create_ata {
+            bail!(
+                "{} has no token account for {}, pass --create-ata or create it with `spl account create`",
+                args.to,
+                args.mint
+            );
+        }
+        status!("🆕 Creating the recipient token account {}", destination);
+        instructions.push(create_associated_token_account_idempotent(
+            &fee_payer.pubkey(),
+            &args.to,
+            &args.mint,
+            &mint.program_id,
+        ));
+    }
+
+    status!(
+        "💸 Sending {} tokens from {} to {}",
+        format_amount(amount, mint.decimals),
+        source,
+        destination
+    );
+
+    if config.transaction.offline() && mint.program_id == spl_token_2022::id() {
+        status!(
+            "⚠️  Signing offline: transfer fees and transfer hooks of the mint are not checked"
+        );
+    }
+
+    // Комісію рахуємо на поточну епоху, програма відхилить переказ, якщо вона не збігається
+    let fee = match &mint.transfer_fee {
+        Some(transfer_fee) => {
+            let epoch = connection
+                .get_epoch_info()
+                .context("Failed to fetch the current epoch")?
+                .epoch;
+            let fee = transfer_fee
+                .calculate_epoch_fee(epoch, amount)
+                .ok_or_else(|| anyhow!("Transfer fee of {} overflows", amount))?;
+            status!(
+                "🧾 Transfer fee: {} tokens withheld, the recipient gets {}",
+                format_amount(fee, mint.decimals),
+                format_amount(amount - fee, mint.decimals)
+            );
+            Some(fee)
+        }
+        None => None,
+    };
+
+    let mut transfer_ix = match fee {
+        Some(fee) => transfer_checked_with_fee(
+            &mint.program_id,
+            &source,
+            &args.mint,
+            &destination,
+            &owner.address,
+            &owner.multisig_signers(),
+            amount,
+            mint.decimals,
+            fee,
+        )?,
+        None => transfer_checked(
+            &mint.program_id,
+            &source,
+            &args.mint,
+            &destination,
+            &owner.address,
+            &owner.multisig_signers(),
+            amount,
+            mint.decimals,
+        )?,
+    };
+
+    if let Some(hook_program_id) = mint.transfer_hook_program_id {
+        let validation = extra_account_metas_address(&args.mint, &hook_program_id);
+        if connection
+            .get_account_with_commitment(&validation, config.commitment)?
+            .value
+            .is_none()
+        {
+            bail!(
+                "Transfer hook {} of {} has no extra account metas account {}, the hook must initialize it before transfers work",
+                hook_program_id,
+                args.mint,
+                validation
+            );
+        }
+        let resolved = resolve_transfer_hook(
+            config,
+            &mut transfer_ix,
+            &source,
+            &args.mint,
+            &destination,
+            &owner.address,
+            amount,
+        )?;
+        status!(
+            "🪝 Transfer hook {}: {} extra accounts resolved",
+            hook_program_id,
+            resolved
+        );
+        output::set("transfer_hook_program_id", hook_program_id.to_string());
+    }
+    instructions.push(transfer_ix);
+
+    output::set("mint", args.mint.to_string());
+    output::set("source", source.to_string());
+    output::set("destination", destination.to_string());
+    output::set("amount", amount.to_string());
+    output::set("ui_amount", format_amount(amount, mint.decimals));
+    output::set("decimals", mint.decimals);
+    if let Some(fee) = fee {
+        output::set("fee", fee.to_string());
+        output::set("ui_fee", format_amount(fee, mint.decimals));
+    }
+
+    let Some(signature) =
+        transaction::process(config, &fee_payer, &owner.signers(), &instructions)?
+    else {
+        return Ok(());
+    };
+
+    status!("✅ Transaction confirmed, signature: {}", signature);
+    status!("🔗 {}", config.cluster.explorer_tx_url(signature));
+
+    Ok(())
+}
+
+/// Validation PDA of a transfer hook: ["extra-account-metas", mint] under the hook program.
+fn extra_account_metas_address(mint: &Pubkey, hook_program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"extra-account-metas", mint.as_ref()], hook_program_id).0
+}
+
+/// Appends the accounts listed in the hook's validation PDA (plus the hook program itself)
+/// to the transfer instruction; returns how many were added.
+fn resolve_transfer_hook(
+    config: &Config,
+    transfer_ix: &mut Instruction,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Result<usize> {
+    let before = transfer_ix.accounts.len();
+
+    // Хелпер асинхронний, але наш RpcClient блокуючий, тож кожен future вже готовий
+    let fetch = |address: Pubkey| {
+        let result: AccountDataResult = config
+            .rpc
+            .get_account_with_commitment(&address, config.commitment)
+            .map(|response| response.value.map(|account| account.data))
+            .map_err(Into::into);
+        std::future::ready(result)
+    };
+    futures::executor::block_on(add_extra_account_metas(
+        transfer_ix,
+        source,
+        mint,
+        destination,
+        authority,
+        amount,
+        fetch,
+    ))
+    .map_err(|err| anyhow!(err).context("Failed to resolve the transfer hook accounts"))?;
+
+    Ok(transfer_ix.accounts.len() - before)
+}
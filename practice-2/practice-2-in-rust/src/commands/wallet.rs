@@ -0,0 +1,281 @@
+use std::{fs, io::IsTerminal, path::PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use bip39::Mnemonic;
+use clap::{Args, Subcommand};
+use serde_json::json;
+use solana_derivation_path::DerivationPath;
+use solana_sdk::{
+    signature::Keypair,
+    signer::{
+        keypair::{keypair_from_seed, keypair_from_seed_and_derivation_path, write_keypair_file},
+        Signer,
+    },
+};
+
+use crate::{output, signer::prompt_secret};
+
+#[derive(Subcommand)]
+pub enum WalletCommand {
+    /// Generate a new BIP39 seed phrase and derive its accounts
+    New(NewWalletArgs),
+
+    /// Re-derive accounts from an existing seed phrase
+    Recover(RecoverWalletArgs),
+}
+
+#[derive(Args)]
+pub struct DerivationArgs {
+    /// Number of accounts to derive
+    #[arg(long, default_value_t = 1)]
+    pub accounts: u32,
+
+    /// First account index, m/44'/501'/<index>'/0'
+    #[arg(long, default_value_t = 0)]
+    pub start: u32,
+
+    /// Custom derivation path instead of the account range, e.g. "m/44'/501'/0'/0'"
+    #[arg(long, conflicts_with_all = ["accounts", "start", "legacy"])]
+    pub derivation_path: Option<String>,
+
+    /// No derivation: the key `solana-keygen new` produces for the same phrase
+    #[arg(long)]
+    pub legacy: bool,
+
+    /// Protect the seed with an extra BIP39 passphrase (asked interactively)
+    #[arg(long)]
+    pub passphrase: bool,
+
+    /// Save every derived keypair as a solana-keygen JSON file in this directory
+    #[arg(long)]
+    pub outdir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct NewWalletArgs {
+    /// Number of words of the seed phrase
+    #[arg(long, default_value_t = 12, value_parser = parse_word_count)]
+    pub words: usize,
+
+    #[command(flatten)]
+    pub derivation: DerivationArgs,
+}
+
+#[derive(Args)]
+pub struct RecoverWalletArgs {
+    #[command(flatten)]
+    pub derivation: DerivationArgs,
+}
+
+fn parse_word_count(value: &str) -> Result<usize, String> {
+    match value.parse() {
+        Ok(words @ (12 | 15 | 18 | 21 | 24)) => Ok(words),
+        _ => Err("word count must be 12, 15, 18, 21 or 24".to_string()),
+    }
+}
+
+pub fn run(command: WalletCommand) -> Result<()> {
+    match command {
+        WalletCommand::New(args) => new(args),
+        WalletCommand::Recover(args) => recover(args),
+    }
+}
+
+fn new(args: NewWalletArgs) -> Result<()> {
+    let mnemonic = Mnemonic::generate(args.words)?;
+
+    output::set("seed_phrase", mnemonic.to_string());
+    status!("🌱 Seed phrase ({} words):", args.words);
+    status!("\n{}\n", mnemonic);
+    status!("⚠️  Write it down and keep it offline, anyone with it controls the wallet.");
+
+    derive(&mnemonic, &args.derivation)
+}
+
+fn recover(args: RecoverWalletArgs) -> Result<()> {
+    let phrase = prompt_secret("🌱 Enter seed phrase: ")?;
+    let mnemonic = Mnemonic::parse_normalized(&phrase).context("Invalid seed phrase")?;
+
+    derive(&mnemonic, &args.derivation)
+}
+
+fn derive(mnemonic: &Mnemonic, args: &DerivationArgs) -> Result<()> {
+    let passphrase = if args.passphrase {
+        read_bip39_passphrase()?
+    } else {
+        String::new()
+    };
+    let seed = mnemonic.to_seed(&passphrase);
+
+    let keypairs = derive_keypairs(&seed, args)?;
+
+    if let Some(outdir) = &args.outdir {
+        fs::create_dir_all(outdir)
+            .with_context(|| format!("Failed to create {}", outdir.display()))?;
+    }
+
+    output::set(
+        "accounts",
+        keypairs
+            .iter()
+            .map(|(path, keypair)| json!({ "path": path, "pubkey": keypair.pubkey().to_string() }))
+            .collect::<Vec<_>>(),
+    );
+
+    for (path, keypair) in &keypairs {
+        status!("{:<22} {}", path, keypair.pubkey());
+
+        if let Some(outdir) = &args.outdir {
+            let file = outdir.join(format!("{}.json", keypair.pubkey()));
+            write_keypair_file(keypair, &file)
+                .map_err(|err| anyhow!("Failed to write {}: {}", file.display(), err))?;
+        }
+    }
+
+    if let Some(outdir) = &args.outdir {
+        status!("📁 Keypairs saved to {}", outdir.display());
+    }
+
+    Ok(())
+}
+
+/// Always asked interactively: `SPL_KEYSTORE_PASSPHRASE` unlocks keystores, and reusing it here
+/// would silently derive a different wallet.
+fn read_bip39_passphrase() -> Result<String> {
+    let passphrase = prompt_secret("🔐 BIP39 passphrase: ")?;
+    if std::io::stdin().is_terminal() {
+        let repeated = prompt_secret("🔐 Repeat BIP39 passphrase: ")?;
+        if repeated != passphrase {
+            bail!("Passphrases do not match");
+        }
+    }
+    Ok(passphrase)
+}
+
+fn derive_keypairs(seed: &[u8], args: &DerivationArgs) -> Result<Vec<(String, Keypair)>> {
+    if args.legacy {
+        // solana-keygen new бере перші 32 байти seed без деривації
+        let keypair = keypair_from_seed(&seed[..32]).map_err(|err| anyhow!("{}", err))?;
+        return Ok(vec![("(no derivation)".to_string(), keypair)]);
+    }
+
+    if let Some(path) = &args.derivation_path {
+        let derivation_path = DerivationPath::from_absolute_path_str(path)
+            .map_err(|err| anyhow!("Invalid derivation path {}: {}", path, err))?;
+        let keypair = keypair_from_seed_and_derivation_path(seed, Some(derivation_path))
+            .map_err(|err| anyhow!("{}", err))?;
+        return Ok(vec![(path.clone(), keypair)]);
+    }
+
+    if args.accounts == 0 {
+        bail!("--accounts must be at least 1");
+    }
+
+    let end = args
+        .start
+        .checked_add(args.accounts)
+        .ok_or_else(|| anyhow!("Account index overflow"))?;
+
+    (args.start..end)
+        .map(|index| {
+            // Шлях Phantom / solana-keygen: m/44'/501'/index'/0'
+            let derivation_path = DerivationPath::new_bip44(Some(index), Some(0));
+            let keypair = keypair_from_seed_and_derivation_path(seed, Some(derivation_path))
+                .map_err(|err| anyhow!("{}", err))?;
+            Ok((format!("m/44'/501'/{}'/0'", index), keypair))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Standard BIP39 test mnemonic; its addresses match solana-keygen and Phantom.
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn args() -> DerivationArgs {
+        DerivationArgs {
+            accounts: 1,
+            start: 0,
+            derivation_path: None,
+            legacy: false,
+            passphrase: false,
+            outdir: None,
+        }
+    }
+
+    fn derive(passphrase: &str, args: &DerivationArgs) -> Vec<(String, String)> {
+        let seed = Mnemonic::parse_normalized(MNEMONIC)
+            .unwrap()
+            .to_seed(passphrase);
+        derive_keypairs(&seed, args)
+            .unwrap()
+            .into_iter()
+            .map(|(path, keypair)| (path, keypair.pubkey().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn bip44_accounts() {
+        let accounts = derive(
+            "",
+            &DerivationArgs {
+                accounts: 2,
+                ..args()
+            },
+        );
+        assert_eq!(
+            accounts,
+            [
+                (
+                    "m/44'/501'/0'/0'".to_string(),
+                    "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk".to_string()
+                ),
+                (
+                    "m/44'/501'/1'/0'".to_string(),
+                    "Hh8QwFUA6MtVu1qAoq12ucvFHNwCcVTV7hpWjeY1Hztb".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_derivation_path() {
+        let accounts = derive(
+            "",
+            &DerivationArgs {
+                derivation_path: Some("m/44'/501'/0'".to_string()),
+                ..args()
+            },
+        );
+        assert_eq!(
+            accounts[0].1,
+            "GjJyeC1r2RgkuoCWMyPYkCWSGSGLcz266EaAkLA27AhL"
+        );
+    }
+
+    #[test]
+    fn legacy_without_derivation() {
+        let accounts = derive(
+            "",
+            &DerivationArgs {
+                legacy: true,
+                ..args()
+            },
+        );
+        assert_eq!(
+            accounts[0].1,
+            "EHqmfkN89RJ7Y33CXM6uCzhVeuywHoJXZZLszBHHZy7o"
+        );
+    }
+
+    #[test]
+    fn bip39_passphrase_changes_the_wallet() {
+        let accounts = derive("TREZOR", &args());
+        assert_eq!(
+            accounts[0].1,
+            "7zSmbu6gKkb6HB7UDPtHYjwCWuBHU1D4TpNZFm4sndQe"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use solana_compute_budget_interface::ComputeBudgetInstruction;
+use solana_sdk::{
+    hash::Hash, instruction::Instruction, message::AddressLookupTableAccount, pubkey::Pubkey,
+};
+
+use crate::{config::Config, output, simulation, transaction};
+
+/// Largest compute unit limit a transaction may request.
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Extra compute units on top of the simulated consumption, in percent.
+const COMPUTE_UNIT_MARGIN: u64 = 10;
+
+/// `getRecentPrioritizationFees` accepts at most 128 accounts.
+const MAX_FEE_ACCOUNTS: usize = 128;
+
+/// Priority fee in micro-lamports per compute unit, fixed or a percentile of recent fees.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriorityFee {
+    Fixed(u64),
+    Percentile(u8),
+}
+
+impl FromStr for PriorityFee {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == "auto" {
+            return Ok(PriorityFee::Percentile(75));
+        }
+        if let Some(percentile) = value.strip_prefix('p') {
+            return match percentile.parse::<u8>() {
+                Ok(percentile) if percentile <= 100 => Ok(PriorityFee::Percentile(percentile)),
+                _ => Err(format!("invalid percentile: {}, expected p0..p100", value)),
+            };
+        }
+        value.parse().map(PriorityFee::Fixed).map_err(|_| {
+            format!(
+                "invalid priority fee: {}, expected micro-lamports, auto or pNN",
+                value
+            )
+        })
+    }
+}
+
+impl fmt::Display for PriorityFee {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PriorityFee::Fixed(micro_lamports) => write!(f, "{}", micro_lamports),
+            PriorityFee::Percentile(percentile) => write!(f, "p{}", percentile),
+        }
+    }
+}
+
+/// SetComputeUnitLimit and SetComputeUnitPrice instructions for `instructions`.
+///
+/// `prefix` holds instructions that must stay first (AdvanceNonceAccount), they are
+/// included in the simulation but not returned.
+pub fn instructions(
+    config: &Config,
+    payer: &Pubkey,
+    prefix: &[Instruction],
+    instructions: &[Instruction],
+    tables: &[AddressLookupTableAccount],
+) -> Result<Vec<Instruction>> {
+    let args = &config.transaction;
+    let offline = args.offline();
+
+    let price = match args.priority_fee {
+        PriorityFee::Fixed(micro_lamports) => micro_lamports,
+        PriorityFee::Percentile(_) if offline => {
+            status!("⚠️  Offline: no priority fee, pass a fixed --priority-fee to add one");
+            0
+        }
+        PriorityFee::Percentile(percentile) => recent_fee(config, instructions, percentile)?,
+    };
+    let price_ix = (price > 0).then(|| ComputeBudgetInstruction::set_compute_unit_price(price));
+
+    let limit = match args.compute_unit_limit {
+        Some(limit) => Some(limit),
+        None if offline => None,
+        None => {
+            let simulated = [
+                prefix,
+                &[ComputeBudgetInstruction::set_compute_unit_limit(
+                    MAX_COMPUTE_UNIT_LIMIT,
+                )],
+                price_ix.as_slice(),
+                instructions,
+            ]
+            .concat();
+            simulate(config, payer, &simulated, tables)?.map(|consumed| {
+                let limit = consumed * (100 + COMPUTE_UNIT_MARGIN) / 100;
+                limit.min(MAX_COMPUTE_UNIT_LIMIT as u64) as u32
+            })
+        }
+    };
+
+    output::set("compute_unit_limit", limit);
+    output::set("priority_fee", price);
+    match limit {
+        Some(limit) => status!(
+            "⛽ Compute units: {}, priority fee: {} micro-lamports/CU",
+            limit,
+            price
+        ),
+        None => status!("⛽ Priority fee: {} micro-lamports/CU", price),
+    }
+
+    Ok(limit
+        .map(ComputeBudgetInstruction::set_compute_unit_limit)
+        .into_iter()
+        .chain(price_ix)
+        .collect())
+}
+
+/// Simulates the transaction and returns the consumed compute units.
+/// With `--dry-run` a failure is left for the dry run report.
+fn simulate(
+    config: &Config,
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    tables: &[AddressLookupTableAccount],
+) -> Result<Option<u64>> {
+    // Blockhash замінює сам вузол (replace_recent_blockhash)
+    let message = transaction::compile(payer, instructions, tables, Hash::default())?;
+    let tx = transaction::unsigned(message);
+    let result = simulation::simulate(config, &tx, &[])?;
+
+    if let Some(err) = &result.err {
+        if config.transaction.dry_run {
+            return Ok(None);
+        }
+        simulation::print_logs(&result);
+        bail!("Transaction simulation failed: {}", err);
+    }
+
+    result
+        .units_consumed
+        .map(Some)
+        .context("Simulation did not report consumed compute units")
+}
+
+/// Percentile of the prioritization fees recently paid for the writable accounts.
+fn recent_fee(config: &Config, instructions: &[Instruction], percentile: u8) -> Result<u64> {
+    let mut accounts: Vec<Pubkey> = Vec::new();
+    for meta in instructions.iter().flat_map(|ix| &ix.accounts) {
+        if meta.is_writable && !accounts.contains(&meta.pubkey) {
+            accounts.push(meta.pubkey);
+        }
+    }
+    accounts.truncate(MAX_FEE_ACCOUNTS);
+
+    let mut fees: Vec<u64> = config
+        .rpc
+        .get_recent_prioritization_fees(&accounts)
+        .context("Failed to get recent prioritization fees")?
+        .iter()
+        .map(|fee| fee.prioritization_fee)
+        .collect();
+    if fees.is_empty() {
+        return Ok(0);
+    }
+
+    fees.sort_unstable();
+    Ok(fees[(fees.len() - 1) * percentile as usize / 100])
+}
@@ -0,0 +1,269 @@
+use std::{fmt, fs, path::PathBuf, str::FromStr};
+
+use anyhow::{Context, Result};
+use clap::Args;
+use serde::Deserialize;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::{CommitmentConfig, CommitmentLevel},
+    rent::Rent,
+    signature::Keypair,
+    signer::Signer,
+};
+
+use crate::{
+    output::OutputFormat,
+    signer::{default_signer_source, SignerSource},
+    transaction::TransactionArgs,
+};
+
+/// Cluster, commitment and signer options shared by every command.
+#[derive(Args)]
+pub struct ConfigArgs {
+    /// Cluster to use: mainnet-beta, devnet, testnet, localhost (or m/d/t/l) or a custom RPC URL
+    #[arg(short = 'u', long = "url", global = true)]
+    pub url: Option<Cluster>,
+
+    /// Commitment level used for queries and confirmations
+    #[arg(long, global = true)]
+    pub commitment: Option<CommitmentLevel>,
+
+    /// Solana CLI configuration file [default: ~/.config/solana/cli/config.yml]
+    #[arg(short = 'C', long = "config", global = true)]
+    pub config_file: Option<PathBuf>,
+
+    /// Default signer: keypair file, file:PATH, env:VAR, base58:SECRET or prompt
+    #[arg(short = 'k', long = "keypair", global = true)]
+    pub keypair: Option<SignerSource>,
+
+    /// Fee payer signer, same formats as --keypair [default: --keypair]
+    #[arg(long, global = true)]
+    pub fee_payer: Option<SignerSource>,
+
+    /// Output format: emoji text, or a JSON result for scripts
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
+    #[command(flatten)]
+    pub transaction: TransactionArgs,
+}
+
+/// Subset of the Solana CLI `config.yml` understood by this tool.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct CliConfig {
+    pub json_rpc_url: Option<String>,
+    pub keypair_path: Option<String>,
+    pub commitment: Option<String>,
+}
+
+impl CliConfig {
+    fn default_path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".config/solana/cli/config.yml"))
+    }
+
+    /// A missing default file is not an error, an explicitly passed one is.
+    fn load(path: Option<&PathBuf>) -> Result<Self> {
+        let (path, explicit) = match path {
+            Some(path) => (path.clone(), true),
+            None => match Self::default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Self::default()),
+            },
+        };
+
+        if !explicit && !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        serde_yaml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config file {}", path.display()))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Cluster {
+    Mainnet,
+    Devnet,
+    Testnet,
+    Localhost,
+    Custom(String),
+}
+
+impl Cluster {
+    pub fn url(&self) -> &str {
+        match self {
+            Cluster::Mainnet => "https://api.mainnet-beta.solana.com",
+            Cluster::Devnet => "https://api.devnet.solana.com",
+            Cluster::Testnet => "https://api.testnet.solana.com",
+            Cluster::Localhost => "http://localhost:8899",
+            Cluster::Custom(url) => url,
+        }
+    }
+
+    // Query-параметр explorer-а для обраного кластера
+    fn explorer_query(&self) -> String {
+        match self {
+            Cluster::Mainnet => String::new(),
+            Cluster::Devnet => "?cluster=devnet".to_string(),
+            Cluster::Testnet => "?cluster=testnet".to_string(),
+            Cluster::Localhost | Cluster::Custom(_) => format!(
+                "?cluster=custom&customUrl={}",
+                url::form_urlencoded::byte_serialize(self.url().as_bytes()).collect::<String>()
+            ),
+        }
+    }
+
+    pub fn explorer_tx_url(&self, signature: impl fmt::Display) -> String {
+        format!(
+            "https://explorer.solana.com/tx/{}{}",
+            signature,
+            self.explorer_query()
+        )
+    }
+
+    pub fn explorer_address_url(&self, address: impl fmt::Display) -> String {
+        format!(
+            "https://explorer.solana.com/address/{}{}",
+            address,
+            self.explorer_query()
+        )
+    }
+}
+
+impl FromStr for Cluster {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let cluster = match value {
+            "m" | "mainnet" | "mainnet-beta" => Cluster::Mainnet,
+            "d" | "devnet" => Cluster::Devnet,
+            "t" | "testnet" => Cluster::Testnet,
+            "l" | "localhost" => Cluster::Localhost,
+            url if url.starts_with("http://") || url.starts_with("https://") => {
+                // Відомі URL повертаємо як відповідні кластери, щоб explorer-посилання були коректні
+                match url.trim_end_matches('/') {
+                    "https://api.mainnet-beta.solana.com" => Cluster::Mainnet,
+                    "https://api.devnet.solana.com" => Cluster::Devnet,
+                    "https://api.testnet.solana.com" => Cluster::Testnet,
+                    "http://localhost:8899" | "http://127.0.0.1:8899" => Cluster::Localhost,
+                    _ => Cluster::Custom(url.to_string()),
+                }
+            }
+            other => return Err(format!("unknown cluster or URL: {}", other)),
+        };
+        Ok(cluster)
+    }
+}
+
+impl fmt::Display for Cluster {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cluster::Mainnet => write!(f, "mainnet-beta"),
+            Cluster::Devnet => write!(f, "devnet"),
+            Cluster::Testnet => write!(f, "testnet"),
+            Cluster::Localhost => write!(f, "localhost"),
+            Cluster::Custom(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+/// Resolved runtime configuration: CLI flags first, then the Solana CLI config file, then devnet.
+pub struct Config {
+    pub cluster: Cluster,
+    pub commitment: CommitmentConfig,
+    pub rpc: RpcClient,
+    pub transaction: TransactionArgs,
+    keypair: Option<SignerSource>,
+    keypair_path: Option<String>,
+    fee_payer: Option<SignerSource>,
+}
+
+impl Config {
+    pub fn load(args: &ConfigArgs) -> Result<Self> {
+        let cli_config = CliConfig::load(args.config_file.as_ref())?;
+
+        let cluster = match (&args.url, &cli_config.json_rpc_url) {
+            (Some(cluster), _) => cluster.clone(),
+            (None, Some(url)) => url.parse().map_err(anyhow::Error::msg)?,
+            (None, None) => Cluster::Devnet,
+        };
+
+        let commitment = match (args.commitment, &cli_config.commitment) {
+            (Some(level), _) => CommitmentConfig { commitment: level },
+            (None, Some(level)) => CommitmentConfig::from_str(level)
+                .map_err(|_| anyhow::anyhow!("Invalid commitment in config file: {}", level))?,
+            (None, None) => CommitmentConfig::confirmed(),
+        };
+
+        let rpc = RpcClient::new_with_commitment(cluster.url().to_string(), commitment);
+
+        Ok(Self {
+            cluster,
+            commitment,
+            rpc,
+            transaction: args.transaction.clone(),
+            keypair: args.keypair.clone(),
+            keypair_path: cli_config.keypair_path,
+            fee_payer: args.fee_payer.clone(),
+        })
+    }
+
+    pub fn default_signer(&self) -> Result<Box<dyn Signer>> {
+        self.default_source()?.signer("default signer")
+    }
+
+    pub fn fee_payer(&self) -> Result<Box<dyn Signer>> {
+        match &self.fee_payer {
+            Some(source) => source.signer("fee payer"),
+            None => self.default_signer(),
+        }
+    }
+
+    /// Loads a command specific signer (authority, owner...) or falls back to the default signer.
+    pub fn signer_or_default(
+        &self,
+        source: Option<&SignerSource>,
+        name: &str,
+    ) -> Result<Box<dyn Signer>> {
+        match source {
+            Some(source) => source.signer(name),
+            None => self.default_signer(),
+        }
+    }
+
+    /// Like `signer_or_default`, but requires the secret key; a `Keypair` can be shared
+    /// between threads.
+    pub fn keypair_or_default(&self, source: Option<&SignerSource>, name: &str) -> Result<Keypair> {
+        match source {
+            Some(source) => source.load(name),
+            None => self.default_source()?.load(name),
+        }
+    }
+
+    pub fn fee_payer_keypair(&self) -> Result<Keypair> {
+        match &self.fee_payer {
+            Some(source) => source.load("fee payer"),
+            None => self.default_source()?.load("fee payer"),
+        }
+    }
+
+    /// Rent-exempt minimum for `space` bytes; offline it uses the default rent parameters.
+    pub fn rent_exempt_minimum(&self, space: usize) -> Result<u64> {
+        if self.transaction.offline() {
+            return Ok(Rent::default().minimum_balance(space));
+        }
+        self.rpc
+            .get_minimum_balance_for_rent_exemption(space)
+            .context("Failed to fetch the rent-exempt minimum")
+    }
+
+    fn default_source(&self) -> Result<SignerSource> {
+        match &self.keypair {
+            Some(source) => Ok(source.clone()),
+            None => default_signer_source(self.keypair_path.as_deref()),
+        }
+    }
+}
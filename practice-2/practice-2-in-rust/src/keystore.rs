@@ -0,0 +1,337 @@
+use std::{
+    env, fs,
+    io::{IsTerminal, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chacha20poly1305::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+    XChaCha20Poly1305, XNonce,
+};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::signer::prompt_secret;
+
+/// Overrides the keystore directory (default `~/.config/spl-cli/keystore`).
+pub const KEYSTORE_DIR_ENV: &str = "SPL_KEYSTORE_DIR";
+/// Non-interactive passphrase for scripts and CI.
+pub const KEYSTORE_PASSPHRASE_ENV: &str = "SPL_KEYSTORE_PASSPHRASE";
+
+const KEYSTORE_VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+// Параметри Argon2id (рекомендація OWASP: 19 MiB, 2 ітерації)
+const ARGON2_M_COST: u32 = 19 * 1024;
+const ARGON2_T_COST: u32 = 2;
+const ARGON2_P_COST: u32 = 1;
+
+// Верхні межі для параметрів з файлу: підроблений файл не повинен з'їсти всю пам'ять
+const MAX_M_COST: u32 = 1024 * 1024;
+const MAX_T_COST: u32 = 16;
+const MAX_P_COST: u32 = 16;
+
+#[derive(Serialize, Deserialize)]
+struct KdfParams {
+    algorithm: String,
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+    salt: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CipherParams {
+    algorithm: String,
+    nonce: String,
+}
+
+/// On-disk format of one encrypted key; the public key stays readable for `keys list`.
+#[derive(Serialize, Deserialize)]
+pub struct KeystoreFile {
+    version: u8,
+    pub pubkey: String,
+    kdf: KdfParams,
+    cipher: CipherParams,
+    ciphertext: String,
+}
+
+pub fn keystore_dir() -> Result<PathBuf> {
+    if let Some(dir) = env::var_os(KEYSTORE_DIR_ENV) {
+        return Ok(PathBuf::from(dir));
+    }
+    dirs::home_dir()
+        .map(|home| home.join(".config/spl-cli/keystore"))
+        .ok_or_else(|| anyhow!("Cannot determine home directory, set {}", KEYSTORE_DIR_ENV))
+}
+
+fn key_path(name: &str) -> Result<PathBuf> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        bail!(
+            "Invalid key name '{}': use letters, digits, '-' and '_'",
+            name
+        );
+    }
+    Ok(keystore_dir()?.join(format!("{}.json", name)))
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+) -> Result<[u8; KEY_LEN]> {
+    let params = Params::new(m_cost, t_cost, p_cost, Some(KEY_LEN))
+        .map_err(|err| anyhow!("Invalid KDF parameters: {}", err))?;
+    let mut key = [0u8; KEY_LEN];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|err| anyhow!("Key derivation failed: {}", err))?;
+    Ok(key)
+}
+
+/// Passphrase from `SPL_KEYSTORE_PASSPHRASE` or an interactive prompt.
+/// New passphrases are asked twice when typed on a terminal.
+pub fn read_passphrase(prompt: &str, confirm: bool) -> Result<String> {
+    if let Ok(passphrase) = env::var(KEYSTORE_PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+
+    let passphrase = prompt_secret(prompt)?;
+    if confirm && std::io::stdin().is_terminal() {
+        let repeated = prompt_secret("🔐 Repeat passphrase: ")?;
+        if repeated != passphrase {
+            bail!("Passphrases do not match");
+        }
+    }
+    Ok(passphrase)
+}
+
+pub fn encrypt(keypair: &Keypair, passphrase: &str) -> Result<KeystoreFile> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(
+        passphrase,
+        &salt,
+        ARGON2_M_COST,
+        ARGON2_T_COST,
+        ARGON2_P_COST,
+    )?;
+
+    let cipher = XChaCha20Poly1305::new(&key.into());
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, keypair.to_bytes().as_slice())
+        .map_err(|_| anyhow!("Encryption failed"))?;
+
+    Ok(KeystoreFile {
+        version: KEYSTORE_VERSION,
+        pubkey: keypair.pubkey().to_string(),
+        kdf: KdfParams {
+            algorithm: "argon2id".to_string(),
+            m_cost: ARGON2_M_COST,
+            t_cost: ARGON2_T_COST,
+            p_cost: ARGON2_P_COST,
+            salt: BASE64.encode(salt),
+        },
+        cipher: CipherParams {
+            algorithm: "xchacha20poly1305".to_string(),
+            nonce: BASE64.encode(nonce),
+        },
+        ciphertext: BASE64.encode(ciphertext),
+    })
+}
+
+pub fn decrypt(file: &KeystoreFile, passphrase: &str) -> Result<Keypair> {
+    if file.version != KEYSTORE_VERSION
+        || file.kdf.algorithm != "argon2id"
+        || file.cipher.algorithm != "xchacha20poly1305"
+    {
+        bail!("Unsupported keystore format (version {})", file.version);
+    }
+
+    let salt = BASE64
+        .decode(&file.kdf.salt)
+        .context("Corrupted keystore salt")?;
+    let nonce = BASE64
+        .decode(&file.cipher.nonce)
+        .context("Corrupted keystore nonce")?;
+    let ciphertext = BASE64
+        .decode(&file.ciphertext)
+        .context("Corrupted keystore ciphertext")?;
+    if nonce.len() != 24 {
+        bail!("Corrupted keystore nonce");
+    }
+    let kdf = &file.kdf;
+    if kdf.m_cost > MAX_M_COST || kdf.t_cost > MAX_T_COST || kdf.p_cost > MAX_P_COST {
+        bail!(
+            "Keystore KDF parameters are too expensive (m_cost {} KiB, t_cost {}, p_cost {}), refusing to derive",
+            kdf.m_cost,
+            kdf.t_cost,
+            kdf.p_cost
+        );
+    }
+
+    let key = derive_key(
+        passphrase,
+        &salt,
+        file.kdf.m_cost,
+        file.kdf.t_cost,
+        file.kdf.p_cost,
+    )?;
+    let plaintext = XChaCha20Poly1305::new(&key.into())
+        .decrypt(XNonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|_| anyhow!("Wrong passphrase or corrupted keystore"))?;
+
+    let keypair = Keypair::try_from(plaintext.as_slice()).context("Failed to create keypair")?;
+    if keypair.pubkey().to_string() != file.pubkey {
+        bail!("Keystore public key does not match the decrypted secret");
+    }
+    Ok(keypair)
+}
+
+pub fn read(name: &str) -> Result<KeystoreFile> {
+    let path = key_path(name)?;
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("Key '{}' not found in keystore ({})", name, path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Corrupted keystore file {}", path.display()))
+}
+
+pub fn save(name: &str, file: &KeystoreFile, overwrite: bool) -> Result<PathBuf> {
+    let path = key_path(name)?;
+    if path.exists() && !overwrite {
+        bail!("Key '{}' already exists, pass --force to overwrite", name);
+    }
+
+    let dir = path.parent().expect("keystore path has a parent");
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    write_private(&path, serde_json::to_string_pretty(file)?.as_bytes())?;
+    Ok(path)
+}
+
+/// Loads and decrypts a key, asking for its passphrase.
+pub fn load(name: &str) -> Result<Keypair> {
+    let file = read(name)?;
+    let passphrase = read_passphrase(&format!("🔐 Passphrase for key '{}': ", name), false)?;
+    decrypt(&file, &passphrase).with_context(|| format!("Failed to unlock key '{}'", name))
+}
+
+/// Keys of the keystore; unreadable or badly named files are reported and skipped.
+pub fn list() -> Result<Vec<(String, Pubkey)>> {
+    let dir = keystore_dir()?;
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut keys = vec![];
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            status!("⚠️  Skipping {}: not a valid key name", path.display());
+            continue;
+        };
+        let pubkey = read(name).and_then(|file| {
+            file.pubkey
+                .parse::<Pubkey>()
+                .context("Corrupted keystore public key")
+        });
+        match pubkey {
+            Ok(pubkey) => keys.push((name.to_string(), pubkey)),
+            Err(err) => status!("⚠️  Skipping {}: {:#}", path.display(), err),
+        }
+    }
+    keys.sort();
+    Ok(keys)
+}
+
+/// Writes a file readable only by the current user.
+pub fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    // mode() діє лише для нового файлу, наявний міг мати ширші права
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))
+            .with_context(|| format!("Failed to restrict permissions of {}", path.display()))?;
+    }
+    file.write_all(contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt_round_trip() {
+        let keypair = Keypair::new();
+        let file = encrypt(&keypair, "correct horse").unwrap();
+        assert_eq!(file.pubkey, keypair.pubkey().to_string());
+
+        let decrypted = decrypt(&file, "correct horse").unwrap();
+        assert_eq!(decrypted.to_bytes(), keypair.to_bytes());
+    }
+
+    #[test]
+    fn wrong_passphrase_fails() {
+        let file = encrypt(&Keypair::new(), "correct horse").unwrap();
+        let err = decrypt(&file, "battery staple").unwrap_err();
+        assert!(err.to_string().contains("Wrong passphrase"));
+    }
+
+    #[test]
+    fn tampered_ciphertext_fails() {
+        let mut file = encrypt(&Keypair::new(), "correct horse").unwrap();
+        let mut ciphertext = BASE64.decode(&file.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        file.ciphertext = BASE64.encode(ciphertext);
+
+        assert!(decrypt(&file, "correct horse").is_err());
+    }
+
+    #[test]
+    fn excessive_kdf_parameters_are_rejected() {
+        let mut file = encrypt(&Keypair::new(), "correct horse").unwrap();
+        file.kdf.m_cost = u32::MAX;
+
+        let err = decrypt(&file, "correct horse").unwrap_err();
+        assert!(err.to_string().contains("too expensive"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn overwriting_restricts_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = env::temp_dir().join(format!("{}.json", Keypair::new().pubkey()));
+        fs::write(&path, b"old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_private(&path, b"new").unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
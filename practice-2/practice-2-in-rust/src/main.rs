@@ -0,0 +1,163 @@
+#[macro_use]
+mod output;
+
+mod authority;
+mod commands;
+mod compute_budget;
+mod config;
+mod keystore;
+mod metaplex;
+mod sender;
+mod signer;
+mod simulation;
+mod token;
+mod transaction;
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+use commands::{
+    account::AccountCommand, approve::ApproveArgs, balances::BalancesArgs,
+    broadcast::BroadcastArgs, burn::BurnArgs, close_account::CloseAccountArgs, freeze::FreezeArgs,
+    grind::GrindArgs, inspect::InspectArgs, keys::KeysCommand, lookup_table::LookupTableCommand,
+    metadata::MetadataCommand, mint::MintCommand, mint_to::MintToArgs, multisig::MultisigCommand,
+    nonce::NonceCommand, payout::PayoutArgs, revoke::RevokeArgs, set_authority::SetAuthorityArgs,
+    sign::SignArgs, transfer::TransferArgs, transfer_token::TransferTokenArgs,
+    wallet::WalletCommand,
+};
+use config::{Config, ConfigArgs};
+
+/// Command line toolkit for SOL transfers and Token-2022 mints.
+#[derive(Parser)]
+#[command(name = "spl", version, about)]
+struct Cli {
+    #[command(flatten)]
+    config: ConfigArgs,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Send SOL to another wallet, optionally attaching a memo
+    Transfer(TransferArgs),
+
+    /// Manage token mints
+    #[command(subcommand)]
+    Mint(MintCommand),
+
+    /// Manage token accounts
+    #[command(subcommand)]
+    Account(AccountCommand),
+
+    /// Mint tokens to a wallet or an existing token account
+    MintTo(MintToArgs),
+
+    /// Send tokens to another wallet's associated token account
+    TransferToken(TransferTokenArgs),
+
+    /// Pay SOL or tokens to every recipient of a CSV file, resuming interrupted runs
+    Payout(PayoutArgs),
+
+    /// Burn tokens from a token account
+    Burn(BurnArgs),
+
+    /// Freeze a token account with the mint's freeze authority
+    Freeze(FreezeArgs),
+
+    /// Thaw a frozen token account with the mint's freeze authority
+    Thaw(FreezeArgs),
+
+    /// Let a delegate transfer or burn up to an amount of tokens
+    Approve(ApproveArgs),
+
+    /// Remove the delegate of a token account
+    Revoke(RevokeArgs),
+
+    /// Change or revoke a mint or token account authority
+    SetAuthority(SetAuthorityArgs),
+
+    /// Close an empty token account and reclaim its rent
+    CloseAccount(CloseAccountArgs),
+
+    /// Create and inspect SPL multisig authorities
+    #[command(subcommand)]
+    Multisig(MultisigCommand),
+
+    /// Manage token metadata
+    #[command(subcommand)]
+    Metadata(MetadataCommand),
+
+    /// Manage durable nonce accounts for long-lived offline transactions
+    #[command(subcommand)]
+    Nonce(NonceCommand),
+
+    /// Manage address lookup tables for v0 transactions with --lookup-table
+    #[command(subcommand)]
+    LookupTable(LookupTableCommand),
+
+    /// Add signatures to a transaction produced with --sign-only
+    Sign(SignArgs),
+
+    /// Send a fully signed transaction produced with --sign-only and `sign`
+    Broadcast(BroadcastArgs),
+
+    /// Decode any account: wallet, mint, token account, multisig, nonce, escrow offer...
+    Inspect(InspectArgs),
+
+    /// List SOL and every token account of a wallet with per-mint totals
+    Balances(BalancesArgs),
+
+    /// Manage the encrypted keystore
+    #[command(subcommand)]
+    Keys(KeysCommand),
+
+    /// Create or recover BIP39 seed phrase wallets
+    #[command(subcommand)]
+    Wallet(WalletCommand),
+
+    /// Grind vanity keypairs on all CPU cores
+    Grind(GrindArgs),
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    // .env може бути відсутнім, це не помилка
+    dotenvy::from_path(".env").ok();
+
+    let cli = Cli::parse();
+    output::init(cli.config.output);
+    output::finish(run(cli))
+}
+
+fn run(cli: Cli) -> anyhow::Result<()> {
+    let config = Config::load(&cli.config)?;
+
+    match cli.command {
+        Command::Keys(command) => commands::keys::run(command),
+        Command::Wallet(command) => commands::wallet::run(command),
+        Command::Grind(args) => commands::grind::run(args),
+        Command::Nonce(command) => commands::nonce::run(&config, command),
+        Command::LookupTable(command) => commands::lookup_table::run(&config, command),
+        Command::Inspect(args) => commands::inspect::run(&config, args),
+        Command::Balances(args) => commands::balances::run(&config, args),
+        Command::Sign(args) => commands::sign::run(&config, args),
+        Command::Broadcast(args) => commands::broadcast::run(&config, args),
+        Command::Transfer(args) => commands::transfer::run(&config, args),
+        Command::Mint(command) => commands::mint::run(&config, command),
+        Command::Account(command) => commands::account::run(&config, command),
+        Command::MintTo(args) => commands::mint_to::run(&config, args),
+        Command::TransferToken(args) => commands::transfer_token::run(&config, args),
+        Command::Payout(args) => commands::payout::run(&config, args),
+        Command::Burn(args) => commands::burn::run(&config, args),
+        Command::Freeze(args) => commands::freeze::freeze(&config, args),
+        Command::Thaw(args) => commands::freeze::thaw(&config, args),
+        Command::Approve(args) => commands::approve::run(&config, args),
+        Command::Revoke(args) => commands::revoke::run(&config, args),
+        Command::SetAuthority(args) => commands::set_authority::run(&config, args),
+        Command::CloseAccount(args) => commands::close_account::run(&config, args),
+        Command::Multisig(command) => commands::multisig::run(&config, command),
+        Command::Metadata(command) => commands::metadata::run(&config, command),
+    }
+}
@@ -0,0 +1,204 @@
+//! Metaplex Token Metadata for classic SPL Token mints, which have no metadata extension.
+//!
+//! The program is not part of `solana-test-validator`, load it from mainnet to test locally:
+//!
+//! ```text
+//! solana program dump -u m metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s mpl_token_metadata.so
+//! solana-test-validator --bpf-program metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s mpl_token_metadata.so
+//! ```
+
+use anyhow::{anyhow, bail, Context, Result};
+use mpl_token_metadata::{
+    accounts::Metadata,
+    instructions::{CreateMetadataAccountV3Builder, UpdateMetadataAccountV2Builder},
+    types::DataV2,
+    MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+use serde_json::json;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::{config::Config, output};
+
+pub use mpl_token_metadata::ID as METAPLEX_PROGRAM_ID;
+
+/// Metadata PDA of a mint: ["metadata", program id, mint].
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Metadata::find_pda(mint).0
+}
+
+/// Fails early with a hint when the cluster (usually a local validator) lacks the program.
+pub fn ensure_program_deployed(config: &Config) -> Result<()> {
+    let program = config
+        .rpc
+        .get_account_with_commitment(&METAPLEX_PROGRAM_ID, config.commitment)?
+        .value;
+    match program {
+        Some(account) if account.executable => Ok(()),
+        _ => bail!(
+            "Metaplex Token Metadata program {} is not deployed on {}, \
+             start the validator with --bpf-program {} mpl_token_metadata.so",
+            METAPLEX_PROGRAM_ID,
+            config.cluster,
+            METAPLEX_PROGRAM_ID
+        ),
+    }
+}
+
+pub fn fetch(config: &Config, mint: &Pubkey) -> Result<Option<Metadata>> {
+    let address = metadata_address(mint);
+    let Some(account) = config
+        .rpc
+        .get_account_with_commitment(&address, config.commitment)?
+        .value
+    else {
+        return Ok(None);
+    };
+    if account.owner != METAPLEX_PROGRAM_ID {
+        bail!("Metadata account {} is not owned by Metaplex", address);
+    }
+
+    let metadata = Metadata::from_bytes(&account.data)
+        .with_context(|| format!("Failed to decode Metaplex metadata {}", address))?;
+    Ok(Some(metadata))
+}
+
+/// Existing metadata of a mint, or an error pointing to `metadata init`.
+pub fn fetch_existing(config: &Config, mint: &Pubkey) -> Result<Metadata> {
+    fetch(config, mint)?.ok_or_else(|| {
+        anyhow!(
+            "Mint {} has no Metaplex metadata, use `metadata init`",
+            mint
+        )
+    })
+}
+
+pub fn check_update_authority(metadata: &Metadata, signer: &Pubkey) -> Result<()> {
+    if !metadata.is_mutable {
+        bail!("Metaplex metadata of {} is immutable", metadata.mint);
+    }
+    if metadata.update_authority != *signer {
+        bail!(
+            "{} is not the update authority of {}, the authority is {}",
+            signer,
+            metadata.mint,
+            metadata.update_authority
+        );
+    }
+    Ok(())
+}
+
+// Metaplex доповнює рядки нульовими байтами до максимальної довжини
+fn trim(value: &str) -> String {
+    value.trim_end_matches('\0').to_string()
+}
+
+/// Editable part of stored metadata, with the zero padding removed.
+pub fn data_of(metadata: &Metadata) -> DataV2 {
+    DataV2 {
+        name: trim(&metadata.name),
+        symbol: trim(&metadata.symbol),
+        uri: trim(&metadata.uri),
+        seller_fee_basis_points: metadata.seller_fee_basis_points,
+        creators: metadata.creators.clone(),
+        collection: metadata.collection.clone(),
+        uses: metadata.uses.clone(),
+    }
+}
+
+/// Token fields only: no royalties, creators, collection or uses.
+pub fn token_data(name: String, symbol: String, uri: String) -> DataV2 {
+    DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    }
+}
+
+pub fn validate(data: &DataV2) -> Result<()> {
+    let limits = [
+        ("name", &data.name, MAX_NAME_LENGTH),
+        ("symbol", &data.symbol, MAX_SYMBOL_LENGTH),
+        ("uri", &data.uri, MAX_URI_LENGTH),
+    ];
+    for (field, value, max) in limits {
+        if value.len() > max {
+            bail!(
+                "Metaplex {} is limited to {} bytes, got {}",
+                field,
+                max,
+                value.len()
+            );
+        }
+    }
+    Ok(())
+}
+
+pub fn create_instruction(
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    payer: &Pubkey,
+    update_authority: &Pubkey,
+    data: DataV2,
+) -> Instruction {
+    CreateMetadataAccountV3Builder::new()
+        .metadata(metadata_address(mint))
+        .mint(*mint)
+        .mint_authority(*mint_authority)
+        .payer(*payer)
+        .update_authority(*update_authority, true)
+        .data(data)
+        .is_mutable(true)
+        .instruction()
+}
+
+/// `UpdateMetadataAccountV2`; every `None` keeps the stored value.
+pub fn update_instruction(
+    mint: &Pubkey,
+    update_authority: &Pubkey,
+    data: Option<DataV2>,
+    new_update_authority: Option<Pubkey>,
+    is_mutable: Option<bool>,
+) -> Instruction {
+    let mut builder = UpdateMetadataAccountV2Builder::new();
+    builder
+        .metadata(metadata_address(mint))
+        .update_authority(*update_authority);
+    if let Some(data) = data {
+        builder.data(data);
+    }
+    if let Some(new_update_authority) = new_update_authority {
+        builder.new_update_authority(new_update_authority);
+    }
+    if let Some(is_mutable) = is_mutable {
+        builder.is_mutable(is_mutable);
+    }
+    builder.instruction()
+}
+
+pub fn print(metadata: &Metadata) {
+    output::set(
+        "metadata",
+        json!({
+            "account": metadata_address(&metadata.mint).to_string(),
+            "name": trim(&metadata.name),
+            "symbol": trim(&metadata.symbol),
+            "uri": trim(&metadata.uri),
+            "update_authority": metadata.update_authority.to_string(),
+            "is_mutable": metadata.is_mutable,
+        }),
+    );
+    status!("📄 Metaplex metadata of {}", metadata.mint);
+    status!("   account: {}", metadata_address(&metadata.mint));
+    status!("   name: {}", trim(&metadata.name));
+    status!("   symbol: {}", trim(&metadata.symbol));
+    status!("   uri: {}", trim(&metadata.uri));
+    status!("   update authority: {}", metadata.update_authority);
+    status!(
+        "   mutable: {}",
+        if metadata.is_mutable { "yes" } else { "no" }
+    );
+}
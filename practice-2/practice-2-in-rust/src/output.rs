@@ -0,0 +1,146 @@
+//! `--output`: emoji text for people or a single JSON result for scripts.
+//!
+//! In the JSON formats the progress lines printed with `status!` go to stderr and stdout
+//! only receives the result object collected with [`set`], or the error.
+
+use std::{
+    process::ExitCode,
+    sync::{Mutex, OnceLock},
+};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use solana_client::client_error::ClientError;
+use solana_sdk::transaction::TransactionError;
+
+use crate::sender::ExpiredError;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable text
+    #[default]
+    Text,
+    /// Pretty printed JSON result
+    Json,
+    /// JSON result on a single line
+    JsonCompact,
+}
+
+static FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+static RESULT: Mutex<Option<Map<String, Value>>> = Mutex::new(None);
+
+/// Prints a progress line: to stdout as text, to stderr when stdout carries JSON.
+macro_rules! status {
+    ($($arg:tt)*) => {
+        if $crate::output::is_json() {
+            eprintln!($($arg)*)
+        } else {
+            println!($($arg)*)
+        }
+    };
+}
+
+pub fn init(format: OutputFormat) {
+    FORMAT.set(format).ok();
+}
+
+pub fn is_json() -> bool {
+    matches!(
+        FORMAT.get(),
+        Some(OutputFormat::Json | OutputFormat::JsonCompact)
+    )
+}
+
+/// Adds a field to the JSON result, text output ignores it.
+pub fn set(key: &str, value: impl Serialize) {
+    if !is_json() {
+        return;
+    }
+    let value = serde_json::to_value(value).unwrap_or(Value::Null);
+    RESULT
+        .lock()
+        .unwrap()
+        .get_or_insert_with(Map::new)
+        .insert(key.to_string(), value);
+}
+
+/// Appends a value to an array field of the JSON result, e.g. one signature per transaction.
+pub fn push(key: &str, value: impl Serialize) {
+    if !is_json() {
+        return;
+    }
+    let value = serde_json::to_value(value).unwrap_or(Value::Null);
+    let mut result = RESULT.lock().unwrap();
+    let entry = result
+        .get_or_insert_with(Map::new)
+        .entry(key)
+        .or_insert_with(|| Value::Array(vec![]));
+    if let Value::Array(values) = entry {
+        values.push(value);
+    }
+}
+
+/// Prints the JSON result or error. Text output keeps the default `Error: ...` report.
+pub fn finish(result: Result<()>) -> Result<ExitCode> {
+    let format = FORMAT.get().copied().unwrap_or_default();
+    if format == OutputFormat::Text {
+        return result.map(|()| ExitCode::SUCCESS);
+    }
+
+    let mut object = RESULT.lock().unwrap().take().unwrap_or_default();
+    let code = match &result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            object.insert("error".to_string(), error_json(err));
+            ExitCode::FAILURE
+        }
+    };
+    object.insert("success".to_string(), Value::Bool(result.is_ok()));
+
+    let object = Value::Object(object);
+    match format {
+        OutputFormat::JsonCompact => println!("{}", object),
+        _ => println!("{}", serde_json::to_string_pretty(&object)?),
+    }
+    Ok(code)
+}
+
+fn error_json(err: &anyhow::Error) -> Value {
+    let (code, transaction_error) = error_code(err);
+    json!({
+        "code": code,
+        "message": format!("{:#}", err),
+        "transaction_error": transaction_error.map(|err| err.to_string()),
+    })
+}
+
+/// One of `transaction_failed`, `transaction_expired`, `rpc_error` or `command_failed`,
+/// with the transaction error when there is one.
+pub fn error_code(err: &anyhow::Error) -> (&'static str, Option<TransactionError>) {
+    let mut code = "command_failed";
+    let mut transaction_error = None;
+
+    for cause in err.chain() {
+        if let Some(err) = cause.downcast_ref::<TransactionError>() {
+            code = "transaction_failed";
+            transaction_error = Some(err.clone());
+            break;
+        }
+        if cause.is::<ExpiredError>() {
+            code = "transaction_expired";
+            break;
+        }
+        if let Some(err) = cause.downcast_ref::<ClientError>() {
+            transaction_error = err.get_transaction_error();
+            code = match transaction_error {
+                Some(_) => "transaction_failed",
+                None => "rpc_error",
+            };
+            break;
+        }
+    }
+
+    (code, transaction_error)
+}
@@ -0,0 +1,163 @@
+use std::{
+    fmt, thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::{TransactionError, VersionedTransaction},
+};
+
+use crate::{config::Config, output, transaction::fetch_nonce};
+
+/// How often an unconfirmed transaction is sent again.
+const REBROADCAST_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How often the signature status is polled.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Until when a signed transaction can still land.
+pub enum Lifetime {
+    /// Recent blockhash valid up to this block height.
+    BlockHeight(u64),
+    /// Recent blockhash with unknown expiry (`--blockhash`, `broadcast`).
+    Blockhash,
+    /// Durable nonce, valid until the nonce account is advanced.
+    Nonce(Pubkey),
+}
+
+impl Lifetime {
+    /// Lifetime of a transaction signed elsewhere.
+    pub fn of(tx: &VersionedTransaction) -> Self {
+        let nonce = tx
+            .uses_durable_nonce()
+            .then(|| tx.message.instructions()[0].accounts.first())
+            .flatten()
+            .and_then(|&index| tx.message.static_account_keys().get(index as usize));
+        match nonce {
+            Some(nonce) => Lifetime::Nonce(*nonce),
+            None => Lifetime::Blockhash,
+        }
+    }
+}
+
+/// A transaction whose blockhash or nonce expired before it landed; nothing was changed.
+#[derive(Debug)]
+pub struct ExpiredError(pub Signature);
+
+impl fmt::Display for ExpiredError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Transaction {} expired before it was confirmed, nothing was changed",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ExpiredError {}
+
+pub enum Outcome {
+    Confirmed(Signature),
+    /// The transaction can no longer land and was not processed.
+    Expired(Signature),
+}
+
+/// Sends `tx` and rebroadcasts it until it is confirmed or can no longer land.
+/// A transaction that executed with an error is returned as `Err`.
+pub fn send_and_confirm(
+    config: &Config,
+    tx: &VersionedTransaction,
+    lifetime: &Lifetime,
+) -> Result<Outcome> {
+    let signature = tx.signatures[0];
+    let mut last_sent: Option<Instant> = None;
+
+    status!("📤 Sending transaction {}", signature);
+    output::set("signature", signature.to_string());
+    loop {
+        if last_sent.is_none_or(|sent| sent.elapsed() >= REBROADCAST_INTERVAL) {
+            // Preflight лише для першої відправки, повторні відправки її пропускають
+            let first = last_sent.is_none();
+            let send_config = RpcSendTransactionConfig {
+                skip_preflight: !first,
+                preflight_commitment: Some(config.commitment.commitment),
+                max_retries: Some(0),
+                ..Default::default()
+            };
+            match config.rpc.send_transaction_with_config(tx, send_config) {
+                Ok(_) => {}
+                Err(err)
+                    if err.get_transaction_error() == Some(TransactionError::AlreadyProcessed) =>
+                {
+                    status!("ℹ️  Transaction was already processed, waiting for confirmation");
+                }
+                Err(err) if first => return Err(err).context("Transaction failed"),
+                Err(_) => {}
+            }
+            last_sent = Some(Instant::now());
+        }
+
+        if let Some(status) = config
+            .rpc
+            .get_signature_status_with_commitment(&signature, config.commitment)
+            .context("Failed to get signature status")?
+        {
+            return confirmed(config, signature, status);
+        }
+
+        if expired(config, tx, lifetime)? {
+            // Остання перевірка з історією: транзакція могла потрапити в блок перед самим завершенням
+            return match config
+                .rpc
+                .get_signature_status_with_commitment_and_history(
+                    &signature,
+                    config.commitment,
+                    true,
+                )
+                .context("Failed to get signature status")?
+            {
+                Some(status) => confirmed(config, signature, status),
+                None => Ok(Outcome::Expired(signature)),
+            };
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn confirmed(
+    config: &Config,
+    signature: Signature,
+    status: Result<(), TransactionError>,
+) -> Result<Outcome> {
+    match status {
+        Ok(()) => {
+            output::set("explorer_url", config.cluster.explorer_tx_url(signature));
+            Ok(Outcome::Confirmed(signature))
+        }
+        Err(err) => {
+            Err(anyhow::Error::new(err).context(format!("Transaction {} failed", signature)))
+        }
+    }
+}
+
+fn expired(config: &Config, tx: &VersionedTransaction, lifetime: &Lifetime) -> Result<bool> {
+    let rpc = &config.rpc;
+    Ok(match lifetime {
+        Lifetime::BlockHeight(last_valid) => {
+            rpc.get_block_height_with_commitment(config.commitment)
+                .context("Failed to get block height")?
+                > *last_valid
+        }
+        Lifetime::Blockhash => !rpc
+            .is_blockhash_valid(tx.message.recent_blockhash(), config.commitment)
+            .context("Failed to check blockhash")?,
+        Lifetime::Nonce(nonce) => {
+            fetch_nonce(config, nonce)?.blockhash() != *tx.message.recent_blockhash()
+        }
+    })
+}
@@ -0,0 +1,286 @@
+use std::{
+    env, fmt,
+    io::IsTerminal,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair},
+    signer::{null_signer::NullSigner, Signer},
+};
+
+use crate::keystore;
+
+/// Environment variable the original practice scripts read the secret key from.
+pub const DEFAULT_KEYPAIR_ENV: &str = "PK";
+
+/// Where a signer's secret key comes from.
+///
+/// Accepted forms on the command line:
+/// `path/to/keypair.json` or `file:path`, `env:VAR`, `base58:SECRET`, `keystore:NAME`,
+/// `prompt` (or `-` / `stdin`) and a bare public key (or `pubkey:KEY`) for signers that sign later.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignerSource {
+    File(PathBuf),
+    Env(String),
+    Base58(String),
+    Keystore(String),
+    Prompt,
+    /// Only the public key is known, the signature is added later with `spl sign`.
+    Pubkey(Pubkey),
+}
+
+impl FromStr for SignerSource {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() {
+            return Err("signer source must not be empty".to_string());
+        }
+
+        let source = match value.split_once(':') {
+            Some(("file", path)) => SignerSource::File(PathBuf::from(path)),
+            Some(("env", var)) => SignerSource::Env(var.to_string()),
+            Some(("base58", secret)) => SignerSource::Base58(secret.to_string()),
+            Some(("keystore", name)) => SignerSource::Keystore(name.to_string()),
+            Some(("prompt", "")) => SignerSource::Prompt,
+            Some(("pubkey", key)) => SignerSource::Pubkey(
+                key.parse()
+                    .map_err(|_| format!("invalid public key: {}", key))?,
+            ),
+            _ => match value {
+                "prompt" | "stdin" | "-" => SignerSource::Prompt,
+                // Існуючий файл має пріоритет над рядком, схожим на публічний ключ
+                path => match Pubkey::from_str(path) {
+                    Ok(pubkey) if !Path::new(path).exists() => SignerSource::Pubkey(pubkey),
+                    _ => SignerSource::File(PathBuf::from(path)),
+                },
+            },
+        };
+        Ok(source)
+    }
+}
+
+impl fmt::Display for SignerSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SignerSource::File(path) => write!(f, "file:{}", path.display()),
+            SignerSource::Env(var) => write!(f, "env:{}", var),
+            // Секрет ніколи не друкуємо
+            SignerSource::Base58(_) => write!(f, "base58:<secret>"),
+            SignerSource::Keystore(name) => write!(f, "keystore:{}", name),
+            SignerSource::Prompt => write!(f, "prompt"),
+            SignerSource::Pubkey(pubkey) => write!(f, "pubkey:{}", pubkey),
+        }
+    }
+}
+
+impl SignerSource {
+    /// Loads the keypair; `name` is used in prompts and error messages (e.g. "fee payer").
+    pub fn load(&self, name: &str) -> Result<Keypair> {
+        match self {
+            SignerSource::File(path) => read_keypair_file(path)
+                .map_err(|err| anyhow!("{}", err))
+                .with_context(|| {
+                    format!("Failed to read {} keypair file {}", name, path.display())
+                }),
+            SignerSource::Env(var) => {
+                let secret = env::var(var)
+                    .with_context(|| format!("No {} key in environment variable {}", name, var))?;
+                parse_secret(&secret).with_context(|| {
+                    format!("Invalid {} key in environment variable {}", name, var)
+                })
+            }
+            SignerSource::Base58(secret) => {
+                keypair_from_base58(secret).with_context(|| format!("Invalid {} base58 key", name))
+            }
+            SignerSource::Keystore(key) => keystore::load(key)
+                .with_context(|| format!("Failed to load {} from keystore", name)),
+            SignerSource::Prompt => {
+                let secret = prompt_secret(&format!(
+                    "🔐 Enter {} secret key (JSON byte array or base58): ",
+                    name
+                ))?;
+                parse_secret(&secret).with_context(|| format!("Invalid {} key from stdin", name))
+            }
+            SignerSource::Pubkey(pubkey) => bail!(
+                "Only the public key of the {} is known ({}), a keypair is required here",
+                name,
+                pubkey
+            ),
+        }
+    }
+
+    /// Like `load`, but a bare public key becomes a signer that leaves its signature empty,
+    /// so the transaction can be completed later (`--sign-only`, `spl sign`).
+    pub fn signer(&self, name: &str) -> Result<Box<dyn Signer>> {
+        match self {
+            SignerSource::Pubkey(pubkey) => Ok(Box::new(NullSigner::new(pubkey))),
+            source => Ok(Box::new(source.load(name)?)),
+        }
+    }
+}
+
+/// Reads a secret from the terminal without echo, or a line from piped stdin.
+pub fn prompt_secret(prompt: &str) -> Result<String> {
+    let secret = if std::io::stdin().is_terminal() {
+        rpassword::prompt_password(prompt)?
+    } else {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        line
+    };
+
+    let secret = secret.trim().to_string();
+    if secret.is_empty() {
+        bail!("No secret entered");
+    }
+    Ok(secret)
+}
+
+/// Parses either a `[u8; 64]` JSON array (solana-keygen format) or a base58 string (Phantom export).
+pub fn parse_secret(secret: &str) -> Result<Keypair> {
+    let secret = secret.trim();
+    if secret.starts_with('[') {
+        let bytes: Vec<u8> = serde_json::from_str(secret).context("Invalid private key format")?;
+        Keypair::try_from(bytes.as_slice()).context("Failed to create keypair")
+    } else {
+        keypair_from_base58(secret)
+    }
+}
+
+fn keypair_from_base58(secret: &str) -> Result<Keypair> {
+    let bytes = bs58::decode(secret.trim())
+        .into_vec()
+        .context("Secret is not valid base58")?;
+    Keypair::try_from(bytes.as_slice()).context("Failed to create keypair")
+}
+
+/// Default signer when `--keypair` is not passed: `PK` from the environment (or `.env`),
+/// then the Solana CLI `keypair_path`, then `~/.config/solana/id.json`.
+pub fn default_signer_source(config_keypair_path: Option<&str>) -> Result<SignerSource> {
+    if env::var_os(DEFAULT_KEYPAIR_ENV).is_some() {
+        return Ok(SignerSource::Env(DEFAULT_KEYPAIR_ENV.to_string()));
+    }
+
+    if let Some(path) = config_keypair_path {
+        return Ok(SignerSource::File(PathBuf::from(path)));
+    }
+
+    let default_path = dirs::home_dir()
+        .map(|home| home.join(".config/solana/id.json"))
+        .filter(|path| path.exists());
+
+    match default_path {
+        Some(path) => Ok(SignerSource::File(path)),
+        None => bail!(
+            "No signer configured: pass --keypair, set {} in .env or run `solana-keygen new`",
+            DEFAULT_KEYPAIR_ENV
+        ),
+    }
+}
+
+/// Drops signers that appear more than once so the same key can be both payer and authority.
+pub fn unique_signers<'a>(signers: &[&'a dyn Signer]) -> Vec<&'a dyn Signer> {
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(signers.len());
+    let mut unique = Vec::with_capacity(signers.len());
+    for signer in signers {
+        let pubkey = signer.pubkey();
+        if !seen.contains(&pubkey) {
+            seen.push(pubkey);
+            unique.push(*signer);
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(value: &str) -> SignerSource {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_json_byte_array() {
+        let keypair = Keypair::new();
+        let json = serde_json::to_string(&keypair.to_bytes().to_vec()).unwrap();
+        assert_eq!(parse_secret(&json).unwrap().pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn parses_base58() {
+        let keypair = Keypair::new();
+        let secret = format!(" {}\n", keypair.to_base58_string());
+        assert_eq!(parse_secret(&secret).unwrap().pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn rejects_invalid_secrets() {
+        assert!(parse_secret("[1, 2, 3]").is_err());
+        assert!(parse_secret("[1, 2,").is_err());
+        assert!(parse_secret("not-base58-0OIl").is_err());
+        assert!(parse_secret(&bs58::encode([7u8; 32]).into_string()).is_err());
+    }
+
+    #[test]
+    fn parses_prefixed_sources() {
+        assert_eq!(
+            parse("file:keys/id.json"),
+            SignerSource::File(PathBuf::from("keys/id.json"))
+        );
+        assert_eq!(parse("env:MY_KEY"), SignerSource::Env("MY_KEY".to_string()));
+        assert_eq!(parse("base58:abc"), SignerSource::Base58("abc".to_string()));
+        assert_eq!(
+            parse("keystore:main"),
+            SignerSource::Keystore("main".to_string())
+        );
+        for prompt in ["prompt:", "prompt", "stdin", "-"] {
+            assert_eq!(parse(prompt), SignerSource::Prompt);
+        }
+    }
+
+    #[test]
+    fn parses_public_keys_and_paths() {
+        let pubkey = Pubkey::new_unique();
+        assert_eq!(parse(&pubkey.to_string()), SignerSource::Pubkey(pubkey));
+        assert_eq!(
+            parse(&format!("pubkey:{}", pubkey)),
+            SignerSource::Pubkey(pubkey)
+        );
+        assert_eq!(
+            parse("~/.config/solana/id.json"),
+            SignerSource::File(PathBuf::from("~/.config/solana/id.json"))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_sources() {
+        assert!("".parse::<SignerSource>().is_err());
+        assert!("pubkey:not-a-key".parse::<SignerSource>().is_err());
+    }
+
+    #[test]
+    fn loads_from_environment_and_base58() {
+        let keypair = Keypair::new();
+        env::set_var("SPL_SIGNER_TEST_KEY", keypair.to_base58_string());
+        let loaded = parse("env:SPL_SIGNER_TEST_KEY").load("test").unwrap();
+        assert_eq!(loaded.pubkey(), keypair.pubkey());
+
+        let source = SignerSource::Base58(keypair.to_base58_string());
+        assert_eq!(source.load("test").unwrap().pubkey(), keypair.pubkey());
+        assert_eq!(source.to_string(), "base58:<secret>");
+    }
+
+    #[test]
+    fn pubkey_source_signs_later() {
+        let pubkey = Pubkey::new_unique();
+        let source = SignerSource::Pubkey(pubkey);
+        assert!(source.load("test").is_err());
+        assert_eq!(source.signer("test").unwrap().pubkey(), pubkey);
+    }
+}
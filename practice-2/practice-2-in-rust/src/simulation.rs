@@ -0,0 +1,246 @@
+//! Transaction simulation shared by compute unit estimation and `--dry-run`.
+
+use anyhow::{Context, Result};
+use serde_json::json;
+use solana_account_decoder_client_types::{UiAccount, UiAccountEncoding};
+use solana_client::{
+    rpc_config::{RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig},
+    rpc_response::RpcSimulateTransactionResult,
+};
+use solana_sdk::{
+    account::Account,
+    message::{AddressLookupTableAccount, VersionedMessage},
+    native_token::Sol,
+    pubkey::Pubkey,
+    transaction::VersionedTransaction,
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    state::{Account as TokenAccount, Mint},
+};
+
+use crate::{
+    config::Config,
+    output,
+    token::{fetch_mint, format_amount},
+};
+
+/// Simulates `tx` without checking signatures, on top of the latest blockhash.
+/// `accounts` are returned in their post-simulation state.
+pub fn simulate(
+    config: &Config,
+    tx: &VersionedTransaction,
+    accounts: &[Pubkey],
+) -> Result<RpcSimulateTransactionResult> {
+    let accounts = (!accounts.is_empty()).then(|| RpcSimulateTransactionAccountsConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        addresses: accounts.iter().map(|address| address.to_string()).collect(),
+    });
+
+    Ok(config
+        .rpc
+        .simulate_transaction_with_config(
+            tx,
+            RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(config.commitment),
+                accounts,
+                ..Default::default()
+            },
+        )
+        .context("Failed to simulate transaction")?
+        .value)
+}
+
+pub fn print_logs(result: &RpcSimulateTransactionResult) {
+    status!("📜 Program logs:");
+    for log in result.logs.iter().flatten() {
+        status!("   {}", log);
+    }
+}
+
+/// Simulates `tx` and prints logs, compute units, balance changes and costs.
+/// `tables` resolve the writable accounts that a v0 message loads from lookup tables.
+pub fn dry_run(
+    config: &Config,
+    tx: &VersionedTransaction,
+    tables: &[AddressLookupTableAccount],
+) -> Result<()> {
+    let message = &tx.message;
+    let mut writable: Vec<Pubkey> = message
+        .static_account_keys()
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| message.is_maybe_writable(*index, None))
+        .map(|(_, address)| *address)
+        .collect();
+    for lookup in message.address_table_lookups().unwrap_or_default() {
+        if let Some(table) = tables.iter().find(|table| table.key == lookup.account_key) {
+            writable.extend(
+                lookup
+                    .writable_indexes
+                    .iter()
+                    .filter_map(|&index| table.addresses.get(index as usize)),
+            );
+        }
+    }
+
+    let accounts_before = config
+        .rpc
+        .get_multiple_accounts(&writable)
+        .context("Failed to fetch accounts")?;
+    let result = simulate(config, tx, &writable)?;
+    let accounts_after: Vec<Option<Account>> = match &result.accounts {
+        Some(accounts) => accounts
+            .iter()
+            .map(|account| account.as_ref().and_then(UiAccount::decode))
+            .collect(),
+        None => vec![None; writable.len()],
+    };
+    let fee = match message {
+        VersionedMessage::Legacy(message) => config.rpc.get_fee_for_message(message),
+        VersionedMessage::V0(message) => config.rpc.get_fee_for_message(message),
+    }
+    .context("Failed to get the transaction fee")?;
+
+    status!("🧪 Dry run, nothing was sent");
+    match &result.err {
+        None => status!("   status: ✅ success"),
+        Some(err) => status!("   status: ❌ {}", err),
+    }
+    print_logs(&result);
+    if let Some(units) = result.units_consumed {
+        status!("⛽ Compute units consumed: {}", units);
+    }
+
+    let mut rent_deposits = 0;
+    let mut rent_refunds = 0;
+    let mut sol_changes = Vec::new();
+    status!("💰 SOL changes:");
+    for (address, (before, after)) in writable
+        .iter()
+        .zip(accounts_before.iter().zip(&accounts_after))
+    {
+        let lamports_before = before.as_ref().map_or(0, |account| account.lamports);
+        let lamports_after = after.as_ref().map_or(0, |account| account.lamports);
+        if lamports_before == lamports_after {
+            continue;
+        }
+
+        let note = if lamports_before == 0 {
+            rent_deposits += lamports_after;
+            " (new account)"
+        } else if lamports_after == 0 {
+            rent_refunds += lamports_before;
+            " (closed)"
+        } else {
+            ""
+        };
+        let delta = signed(lamports_before, lamports_after, |amount| {
+            Sol(amount).to_string()
+        });
+        status!("   {}: {}{}", address, delta, note);
+        sol_changes.push(json!({
+            "address": address.to_string(),
+            "lamports_before": lamports_before,
+            "lamports_after": lamports_after,
+        }));
+    }
+
+    let mut token_changes = Vec::new();
+    for (address, (before, after)) in writable
+        .iter()
+        .zip(accounts_before.iter().zip(&accounts_after))
+    {
+        let balance_before = before.as_ref().and_then(token_balance);
+        let balance_after = after.as_ref().and_then(token_balance);
+        let Some(mint) = balance_after.or(balance_before).map(|(mint, _)| mint) else {
+            continue;
+        };
+        let amount_before = balance_before.map_or(0, |(_, amount)| amount);
+        let amount_after = balance_after.map_or(0, |(_, amount)| amount);
+        if amount_before == amount_after {
+            continue;
+        }
+
+        if token_changes.is_empty() {
+            status!("🪙 Token changes:");
+        }
+        let decimals = mint_decimals(config, &mint, &writable, &accounts_after)?;
+        let delta = signed(amount_before, amount_after, |amount| {
+            format_amount(amount, decimals)
+        });
+        status!("   {} (mint {}): {}", address, mint, delta);
+        token_changes.push(json!({
+            "address": address.to_string(),
+            "mint": mint.to_string(),
+            "decimals": decimals,
+            "amount_before": amount_before.to_string(),
+            "amount_after": amount_after.to_string(),
+        }));
+    }
+
+    status!("💸 Network fee: {}", Sol(fee));
+    if rent_deposits > 0 {
+        status!("🏦 Rent deposits: {}", Sol(rent_deposits));
+    }
+    if rent_refunds > 0 {
+        status!("🏦 Rent refunds: {}", Sol(rent_refunds));
+    }
+
+    output::set(
+        "simulation",
+        json!({
+            "success": result.err.is_none(),
+            "logs": result.logs.unwrap_or_default(),
+            "units_consumed": result.units_consumed,
+            "sol_changes": sol_changes,
+            "token_changes": token_changes,
+            "fee": fee,
+            "rent_deposits": rent_deposits,
+            "rent_refunds": rent_refunds,
+        }),
+    );
+
+    if let Some(err) = result.err {
+        return Err(anyhow::Error::new(err).context("Simulation failed"));
+    }
+    Ok(())
+}
+
+fn signed(before: u64, after: u64, format: impl Fn(u64) -> String) -> String {
+    if after >= before {
+        format!("+{}", format(after - before))
+    } else {
+        format!("-{}", format(before - after))
+    }
+}
+
+fn token_balance(account: &Account) -> Option<(Pubkey, u64)> {
+    if account.owner != spl_token::id() && account.owner != spl_token_2022::id() {
+        return None;
+    }
+    let state = StateWithExtensions::<TokenAccount>::unpack(&account.data).ok()?;
+    Some((state.base.mint, state.base.amount))
+}
+
+/// Decimals of `mint`, taken from the simulated state when the transaction touches the mint.
+fn mint_decimals(
+    config: &Config,
+    mint: &Pubkey,
+    addresses: &[Pubkey],
+    accounts: &[Option<Account>],
+) -> Result<u8> {
+    let simulated = addresses
+        .iter()
+        .zip(accounts)
+        .find(|(address, _)| *address == mint)
+        .and_then(|(_, account)| account.as_ref())
+        .and_then(|account| StateWithExtensions::<Mint>::unpack(&account.data).ok());
+
+    match simulated {
+        Some(state) => Ok(state.base.decimals),
+        None => Ok(fetch_mint(config, mint)?.decimals),
+    }
+}
@@ -0,0 +1,210 @@
+//! Helpers shared by commands that work with both SPL Token and Token-2022 mints.
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, ValueEnum};
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use spl_token_2022::{
+    amount_to_ui_amount_string_trimmed,
+    extension::{
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        transfer_hook, BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{Account as TokenAccount, Mint},
+    try_ui_amount_into_amount,
+};
+
+use crate::config::Config;
+
+/// Decoded mint together with the token program that owns it.
+pub struct MintInfo {
+    pub program_id: Pubkey,
+    pub decimals: u8,
+    pub mint_authority: Option<Pubkey>,
+    pub freeze_authority: Option<Pubkey>,
+    /// Token-2022 transfer fee schedule, if the mint charges one.
+    pub transfer_fee: Option<TransferFeeConfig>,
+    /// Program called on every transfer of a Token-2022 transfer hook mint.
+    pub transfer_hook_program_id: Option<Pubkey>,
+}
+
+/// Decoded token account with the Token-2022 fees it holds.
+pub struct TokenAccountInfo {
+    pub account: TokenAccount,
+    /// Token-2022 transfer fees withheld in the account, they block closing it.
+    pub withheld_amount: u64,
+}
+
+/// Token program selectable on the command line.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum TokenProgram {
+    /// Classic SPL Token program
+    Token,
+    /// Token-2022 (token extensions) program
+    #[value(name = "token-2022")]
+    Token2022,
+}
+
+impl TokenProgram {
+    pub fn id(self) -> Pubkey {
+        match self {
+            TokenProgram::Token => spl_token::id(),
+            TokenProgram::Token2022 => spl_token_2022::id(),
+        }
+    }
+}
+
+/// Mint details that `--sign-only --blockhash` cannot fetch, so they are passed explicitly.
+#[derive(Args)]
+pub struct OfflineMintArgs {
+    /// Decimals of the mint, required to sign offline
+    #[arg(long)]
+    pub decimals: Option<u8>,
+
+    /// Token program that owns the mint, required to sign offline
+    #[arg(long, value_enum)]
+    pub token_program: Option<TokenProgram>,
+}
+
+impl OfflineMintArgs {
+    /// Fetches the mint and checks it against the flags that were passed. Offline the mint is
+    /// built from the flags alone, without authorities or extensions.
+    pub fn mint(&self, config: &Config, mint: &Pubkey) -> Result<MintInfo> {
+        if config.transaction.offline() {
+            let (Some(decimals), Some(token_program)) = (self.decimals, self.token_program) else {
+                bail!(
+                    "Signing offline cannot fetch mint {}: pass --decimals and --token-program",
+                    mint
+                );
+            };
+            return Ok(MintInfo {
+                program_id: token_program.id(),
+                decimals,
+                mint_authority: None,
+                freeze_authority: None,
+                transfer_fee: None,
+                transfer_hook_program_id: None,
+            });
+        }
+
+        let info = fetch_mint(config, mint)?;
+        if let Some(decimals) = self.decimals.filter(|decimals| *decimals != info.decimals) {
+            bail!(
+                "Mint {} has {} decimals, not {}",
+                mint,
+                info.decimals,
+                decimals
+            );
+        }
+        if let Some(token_program) = self
+            .token_program
+            .filter(|token_program| token_program.id() != info.program_id)
+        {
+            bail!(
+                "Mint {} is owned by {}, not {}",
+                mint,
+                info.program_id,
+                token_program.id()
+            );
+        }
+        Ok(info)
+    }
+}
+
+/// Fetches an account that must belong to SPL Token or Token-2022; `owner` is the program id.
+pub fn fetch_token_program_account(config: &Config, address: &Pubkey) -> Result<Account> {
+    let account = config
+        .rpc
+        .get_account_with_commitment(address, config.commitment)?
+        .value
+        .ok_or_else(|| anyhow!("Account {} not found", address))?;
+    if account.owner != spl_token::id() && account.owner != spl_token_2022::id() {
+        bail!(
+            "{} is not owned by the SPL Token or Token-2022 program",
+            address
+        );
+    }
+    Ok(account)
+}
+
+pub fn fetch_mint(config: &Config, mint: &Pubkey) -> Result<MintInfo> {
+    let account = fetch_token_program_account(config, mint)?;
+
+    // Мінти з розширеннями довші за Mint::LEN, StateWithExtensions розбирає обидва варіанти
+    let state = StateWithExtensions::<Mint>::unpack(&account.data)
+        .with_context(|| format!("Account {} is not a mint", mint))?;
+
+    Ok(MintInfo {
+        program_id: account.owner,
+        decimals: state.base.decimals,
+        mint_authority: state.base.mint_authority.into(),
+        freeze_authority: state.base.freeze_authority.into(),
+        transfer_fee: state.get_extension::<TransferFeeConfig>().ok().copied(),
+        transfer_hook_program_id: transfer_hook::get_program_id(&state),
+    })
+}
+
+/// Fetches a token account and checks that it holds `mint`.
+pub fn fetch_token_account(
+    config: &Config,
+    address: &Pubkey,
+    mint: &Pubkey,
+) -> Result<TokenAccountInfo> {
+    let account = fetch_token_program_account(config, address)?;
+    let state = StateWithExtensions::<TokenAccount>::unpack(&account.data)
+        .with_context(|| format!("Account {} is not a token account", address))?;
+    if state.base.mint != *mint {
+        bail!(
+            "Token account {} holds {}, not {}",
+            address,
+            state.base.mint,
+            mint
+        );
+    }
+
+    let withheld_amount = state
+        .get_extension::<TransferFeeAmount>()
+        .map(|fee| u64::from(fee.withheld_amount))
+        .unwrap_or_default();
+
+    Ok(TokenAccountInfo {
+        account: state.base,
+        withheld_amount,
+    })
+}
+
+/// Converts a UI amount such as "12.5" into base units without going through floats.
+/// Zero is rejected, a transaction moving nothing only costs fees.
+pub fn parse_amount(value: &str, decimals: u8) -> Result<u64> {
+    try_ui_amount_into_amount(value.to_string(), decimals)
+        .ok()
+        .filter(|amount| *amount > 0)
+        .ok_or_else(|| {
+            anyhow!(
+                "Invalid amount '{}': expected a positive number with at most {} decimal places",
+                value,
+                decimals
+            )
+        })
+}
+
+pub fn format_amount(amount: u64, decimals: u8) -> String {
+    amount_to_ui_amount_string_trimmed(amount, decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ui_amounts() {
+        assert_eq!(parse_amount("12.5", 6).unwrap(), 12_500_000);
+        assert_eq!(parse_amount("1", 0).unwrap(), 1);
+    }
+
+    #[test]
+    fn rejects_zero_and_invalid_amounts() {
+        for value in ["0", "0.000", "-1", "abc", "1.0000001"] {
+            assert!(parse_amount(value, 6).is_err(), "{} was accepted", value);
+        }
+    }
+}
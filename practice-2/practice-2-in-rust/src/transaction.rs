@@ -0,0 +1,396 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use clap::Args;
+use serde_json::json;
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+use solana_client::nonce_utils;
+use solana_nonce::state::Data as NonceData;
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, AddressLookupTableAccount, Message, VersionedMessage},
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+    signature::{Signature, Signer},
+    transaction::VersionedTransaction,
+};
+use solana_system_interface::instruction as system_instruction;
+
+use crate::{
+    compute_budget::{self, PriorityFee},
+    config::Config,
+    output,
+    sender::{self, ExpiredError, Lifetime, Outcome},
+    signer::{unique_signers, SignerSource},
+    simulation,
+};
+
+/// Sends of a transaction re-signed with a fresh blockhash after the previous one expired.
+const MAX_SEND_ATTEMPTS: usize = 3;
+
+/// ComputeBudget program id and its two instructions, added by `process`.
+const COMPUTE_BUDGET_RESERVE: usize = 64;
+
+/// Advance-nonce instruction added with `--nonce`: the nonce account, sysvar, system program
+/// and authority keys, the authority's signature and the instruction itself.
+const NONCE_RESERVE: usize = 4 * 32 + 64 + 16;
+
+/// Options of every command that builds a transaction.
+#[derive(Args, Clone)]
+pub struct TransactionArgs {
+    /// Sign with the available keys and print the transaction instead of sending it
+    #[arg(long, global = true)]
+    pub sign_only: bool,
+
+    /// Simulate and print logs, balance changes and costs instead of sending
+    #[arg(long, global = true, conflicts_with = "sign_only")]
+    pub dry_run: bool,
+
+    /// Blockhash to sign with instead of querying the cluster (the stored nonce with --nonce)
+    #[arg(long, global = true)]
+    pub blockhash: Option<Hash>,
+
+    /// Also write the serialized transaction into this file (--sign-only and `sign`)
+    #[arg(long, global = true)]
+    pub tx_file: Option<PathBuf>,
+
+    /// Durable nonce account to use instead of a recent blockhash
+    #[arg(long, global = true)]
+    pub nonce: Option<Pubkey>,
+
+    /// Nonce authority signer, same formats as --keypair [default: --keypair]
+    #[arg(long, global = true, requires = "nonce")]
+    pub nonce_authority: Option<SignerSource>,
+
+    /// Priority fee: micro-lamports per compute unit, `auto` (p75) or a recent fee percentile like p90
+    #[arg(long, global = true, default_value = "auto")]
+    pub priority_fee: PriorityFee,
+
+    /// Compute unit limit to request instead of simulating the transaction
+    #[arg(long, global = true)]
+    pub compute_unit_limit: Option<u32>,
+
+    /// Address lookup table to build a v0 transaction with (repeatable)
+    #[arg(long = "lookup-table", global = true)]
+    pub lookup_tables: Vec<Pubkey>,
+}
+
+impl TransactionArgs {
+    /// `--sign-only` with `--blockhash` never touches the cluster.
+    pub fn offline(&self) -> bool {
+        self.sign_only && self.blockhash.is_some()
+    }
+}
+
+/// Signs `instructions` with every available signer and sends them, or with `--sign-only`
+/// prints the partially signed transaction and with `--dry-run` only simulates it.
+/// Returns the signature once confirmed.
+pub fn process(
+    config: &Config,
+    payer: &dyn Signer,
+    signers: &[&dyn Signer],
+    instructions: &[Instruction],
+) -> Result<Option<Signature>> {
+    process_with(config, payer, signers, instructions, |_| Ok(()))
+}
+
+/// Like [`process`], calling `before_send` with every signed transaction right before it is
+/// sent, including one re-signed after its blockhash expired.
+pub fn process_with(
+    config: &Config,
+    payer: &dyn Signer,
+    signers: &[&dyn Signer],
+    instructions: &[Instruction],
+    mut before_send: impl FnMut(&VersionedTransaction) -> Result<()>,
+) -> Result<Option<Signature>> {
+    let mut prefix = Vec::new();
+    let mut signers = [&[payer], signers].concat();
+
+    let nonce_authority = match &config.transaction.nonce {
+        Some(_) => Some(config.signer_or_default(
+            config.transaction.nonce_authority.as_ref(),
+            "nonce authority",
+        )?),
+        None => None,
+    };
+
+    let (blockhash, mut lifetime) = match (&config.transaction.nonce, &nonce_authority) {
+        (Some(nonce), Some(authority)) => {
+            // AdvanceNonceAccount має бути першою інструкцією транзакції
+            prefix.push(system_instruction::advance_nonce_account(
+                nonce,
+                &authority.pubkey(),
+            ));
+            signers.push(authority.as_ref());
+
+            let blockhash = match config.transaction.blockhash {
+                Some(blockhash) => blockhash,
+                None => {
+                    let data = fetch_nonce(config, nonce)?;
+                    if data.authority != authority.pubkey() {
+                        bail!(
+                            "Nonce authority of {} is {}, not {}",
+                            nonce,
+                            data.authority,
+                            authority.pubkey()
+                        );
+                    }
+                    data.blockhash()
+                }
+            };
+            (blockhash, Lifetime::Nonce(*nonce))
+        }
+        _ => match config.transaction.blockhash {
+            Some(blockhash) => (blockhash, Lifetime::Blockhash),
+            None => latest_blockhash(config)?,
+        },
+    };
+
+    let tables = fetch_lookup_tables(config)?;
+    let budget =
+        compute_budget::instructions(config, &payer.pubkey(), &prefix, instructions, &tables)?;
+    let instructions = [prefix, budget, instructions.to_vec()].concat();
+
+    let message = compile(&payer.pubkey(), &instructions, &tables, blockhash)?;
+    let mut tx = unsigned(message);
+    // Підписувачі, для яких відомий лише публічний ключ, залишають порожній підпис
+    partial_sign(&mut tx, &unique_signers(&signers))?;
+
+    if config.transaction.dry_run {
+        simulation::dry_run(config, &tx, &tables)?;
+        return Ok(None);
+    }
+
+    if config.transaction.sign_only {
+        export(&tx, config.transaction.tx_file.as_ref())?;
+        return Ok(None);
+    }
+
+    let missing = missing_signers(&tx);
+    if !missing.is_empty() {
+        bail!(
+            "Missing signatures of {}: pass their keypairs or use --sign-only and `spl sign`",
+            join(&missing)
+        );
+    }
+
+    let mut attempt = 1;
+    loop {
+        before_send(&tx)?;
+        match sender::send_and_confirm(config, &tx, &lifetime)? {
+            Outcome::Confirmed(signature) => return Ok(Some(signature)),
+            Outcome::Expired(signature) => {
+                // Перепідписувати безпечно лише власний blockhash: прострочена транзакція вже не потрапить у блок
+                if !matches!(lifetime, Lifetime::BlockHeight(_)) || attempt == MAX_SEND_ATTEMPTS {
+                    return Err(ExpiredError(signature).into());
+                }
+                attempt += 1;
+                status!(
+                    "🔁 Blockhash expired, re-signing with a fresh one (attempt {}/{})",
+                    attempt,
+                    MAX_SEND_ATTEMPTS
+                );
+                let (blockhash, fresh) = latest_blockhash(config)?;
+                tx.message.set_recent_blockhash(blockhash);
+                tx.signatures.fill(Signature::default());
+                partial_sign(&mut tx, &unique_signers(&signers))?;
+                lifetime = fresh;
+            }
+        }
+    }
+}
+
+fn latest_blockhash(config: &Config) -> Result<(Hash, Lifetime)> {
+    let (blockhash, last_valid_block_height) = config
+        .rpc
+        .get_latest_blockhash_with_commitment(config.commitment)
+        .context("Failed to get blockhash")?;
+    Ok((blockhash, Lifetime::BlockHeight(last_valid_block_height)))
+}
+
+/// Reads an initialized nonce account: its authority and the stored durable blockhash.
+pub fn fetch_nonce(config: &Config, address: &Pubkey) -> Result<NonceData> {
+    let account = nonce_utils::get_account_with_commitment(&config.rpc, address, config.commitment)
+        .with_context(|| format!("Failed to fetch nonce account {}", address))?;
+    let data = nonce_utils::data_from_account(&account)
+        .with_context(|| format!("{} is not an initialized nonce account", address))?;
+    Ok(data.clone())
+}
+
+/// Reads the `--lookup-table` accounts for compiling a v0 message.
+pub fn fetch_lookup_tables(config: &Config) -> Result<Vec<AddressLookupTableAccount>> {
+    let addresses = &config.transaction.lookup_tables;
+    if addresses.is_empty() {
+        return Ok(Vec::new());
+    }
+    if config.transaction.offline() {
+        bail!("--lookup-table needs the cluster to read the tables, drop --blockhash to sign with them");
+    }
+
+    let accounts = config
+        .rpc
+        .get_multiple_accounts(addresses)
+        .context("Failed to fetch lookup tables")?;
+    addresses
+        .iter()
+        .zip(accounts)
+        .map(|(address, account)| {
+            let account =
+                account.with_context(|| format!("Lookup table {} does not exist", address))?;
+            let table = AddressLookupTable::deserialize(&account.data)
+                .map_err(|err| anyhow::anyhow!("{} is not a lookup table: {}", address, err))?;
+            Ok(AddressLookupTableAccount {
+                key: *address,
+                addresses: table.addresses.to_vec(),
+            })
+        })
+        .collect()
+}
+
+/// Legacy message without lookup tables, otherwise a v0 message that loads the
+/// accounts found in `tables` by index.
+pub fn compile(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    tables: &[AddressLookupTableAccount],
+    blockhash: Hash,
+) -> Result<VersionedMessage> {
+    if tables.is_empty() {
+        return Ok(VersionedMessage::Legacy(Message::new_with_blockhash(
+            instructions,
+            Some(payer),
+            &blockhash,
+        )));
+    }
+    let message = v0::Message::try_compile(payer, instructions, tables, blockhash)
+        .context("Failed to compile a v0 message")?;
+    Ok(VersionedMessage::V0(message))
+}
+
+/// Whether `instructions` still fit into one packet once `process` adds its own instructions.
+pub fn fits(
+    config: &Config,
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    tables: &[AddressLookupTableAccount],
+) -> Result<bool> {
+    let message = compile(payer, instructions, tables, Hash::default())?;
+    let mut size = bincode::serialized_size(&unsigned(message))? as usize + COMPUTE_BUDGET_RESERVE;
+    if config.transaction.nonce.is_some() {
+        size += NONCE_RESERVE;
+    }
+    Ok(size <= PACKET_DATA_SIZE)
+}
+
+/// Transaction with an empty signature for every required signer.
+pub fn unsigned(message: VersionedMessage) -> VersionedTransaction {
+    VersionedTransaction {
+        signatures: vec![Signature::default(); message.header().num_required_signatures as usize],
+        message,
+    }
+}
+
+/// Adds the signatures of `signers`, keeping the ones already present.
+/// A signer without a secret key (`NullSigner`) leaves its signature empty.
+pub fn partial_sign(tx: &mut VersionedTransaction, signers: &[&dyn Signer]) -> Result<()> {
+    let message = tx.message.serialize();
+    for signer in signers {
+        let pubkey = signer.pubkey();
+        let index = signer_keys(tx)
+            .iter()
+            .position(|key| *key == pubkey)
+            .with_context(|| format!("{} is not a signer of this transaction", pubkey))?;
+        let signature = signer.try_sign_message(&message)?;
+        if signature != Signature::default() {
+            tx.signatures[index] = signature;
+        }
+    }
+    Ok(())
+}
+
+pub fn encode(tx: &VersionedTransaction) -> Result<String> {
+    Ok(BASE64.encode(bincode::serialize(tx)?))
+}
+
+/// Reads a transaction from a base64 string or a file with base64 text or raw bytes (`tx.bin`).
+pub fn read(input: &str) -> Result<VersionedTransaction> {
+    let path = PathBuf::from(input);
+    let bytes = if path.is_file() {
+        let contents =
+            fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        match std::str::from_utf8(&contents)
+            .ok()
+            .and_then(|text| BASE64.decode(text.trim()).ok())
+        {
+            Some(decoded) => decoded,
+            None => contents,
+        }
+    } else {
+        BASE64
+            .decode(input.trim())
+            .context("Transaction is neither a file nor valid base64")?
+    };
+
+    bincode::deserialize(&bytes).context("Failed to decode transaction")
+}
+
+/// Required signers that have not signed yet.
+pub fn missing_signers(tx: &VersionedTransaction) -> Vec<Pubkey> {
+    signer_keys(tx)
+        .iter()
+        .zip(&tx.signatures)
+        .filter(|(_, signature)| **signature == Signature::default())
+        .map(|(pubkey, _)| *pubkey)
+        .collect()
+}
+
+pub fn signer_keys(tx: &VersionedTransaction) -> &[Pubkey] {
+    &tx.message.static_account_keys()[..tx.message.header().num_required_signatures as usize]
+}
+
+pub fn print_signers(tx: &VersionedTransaction) {
+    let valid = tx.verify_with_results();
+    let mut signers = Vec::new();
+    status!("✍️  Signers:");
+    for (index, pubkey) in signer_keys(tx).iter().enumerate() {
+        let state = if tx.signatures[index] == Signature::default() {
+            status!("   ❌ {} (missing)", pubkey);
+            "missing"
+        } else if valid[index] {
+            status!("   ✅ {}", pubkey);
+            "signed"
+        } else {
+            status!("   ⚠️  {} (invalid signature)", pubkey);
+            "invalid"
+        };
+        signers.push(json!({ "pubkey": pubkey.to_string(), "signature": state }));
+    }
+    output::set("signers", signers);
+}
+
+/// Prints signer status and the base64 transaction, optionally saving it into `file`.
+pub fn export(tx: &VersionedTransaction, file: Option<&PathBuf>) -> Result<()> {
+    let encoded = encode(tx)?;
+    print_signers(tx);
+    status!("📝 Transaction (base64):");
+    status!("{}", encoded);
+    output::set("transaction", &encoded);
+
+    if let Some(path) = file {
+        fs::write(path, &encoded).with_context(|| format!("Failed to write {}", path.display()))?;
+        status!("📁 Saved to {}", path.display());
+        output::set("tx_file", path);
+    }
+
+    Ok(())
+}
+
+fn join(pubkeys: &[Pubkey]) -> String {
+    pubkeys
+        .iter()
+        .map(|pubkey| pubkey.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}